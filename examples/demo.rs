@@ -9,6 +9,7 @@ struct Application;
 impl App for Application {
     type RenderProcessHandler = ();
     type BrowserProcessHandler = ();
+    type ResourceBundleHandler = ();
 }
 
 #[derive(Debug)]
@@ -18,6 +19,15 @@ impl Client for DemoClient {
     type RenderHandler = ();
     type LoadHandler = ();
     type LifeSpanHandler = ();
+    type DownloadHandler = ();
+    type RequestHandler = ();
+    type DisplayHandler = ();
+    type KeyboardHandler = ();
+    type FocusHandler = ();
+    type ContextMenuHandler = ();
+    type JsDialogHandler = ();
+    type DialogHandler = ();
+    type PermissionHandler = ();
 }
 
 #[derive(Debug)]
@@ -60,7 +70,8 @@ fn main() {
     let browser_view = dbg!(cef::create_browser_view(
         Some(client),
         url,
-        browser_settings
+        browser_settings,
+        None
     ));
 
     let delegate = DemoWindow { browser_view };
@@ -70,7 +81,8 @@ fn main() {
     //     window_info,
     //     Some(client),
     //     url,
-    //     browser_settings
+    //     browser_settings,
+    //     None
     // ));
 
     cef::run_message_loop();
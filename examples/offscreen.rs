@@ -19,6 +19,7 @@ struct Application;
 impl App for Application {
     type RenderProcessHandler = ();
     type BrowserProcessHandler = ();
+    type ResourceBundleHandler = ();
 }
 #[derive(Debug)]
 struct DemoLifeSpanHandler;
@@ -170,6 +171,15 @@ impl Client for DemoClient {
     type RenderHandler = DemoRenderHandler;
     type LoadHandler = DemoLoadHandler;
     type LifeSpanHandler = DemoLifeSpanHandler;
+    type DownloadHandler = ();
+    type RequestHandler = ();
+    type DisplayHandler = ();
+    type KeyboardHandler = ();
+    type FocusHandler = ();
+    type ContextMenuHandler = ();
+    type JsDialogHandler = ();
+    type DialogHandler = ();
+    type PermissionHandler = ();
 
     fn get_render_handler(&self) -> Option<&DemoRenderHandler> {
         Some(&self.render_handler)
@@ -222,7 +232,8 @@ impl Task for BrowserTask {
             window_info,
             Some(client),
             url,
-            browser_settings
+            browser_settings,
+            None
         ));
 
         if let Some(host) = browser.get_host() {
@@ -3,19 +3,22 @@ use std::ptr::null_mut;
 use cef_sys::{
     cef_app_t, cef_browser_process_handler_t, cef_command_line_t, cef_do_message_loop_work,
     cef_execute_process, cef_get_exit_code, cef_initialize, cef_quit_message_loop,
-    cef_render_process_handler_t, cef_run_message_loop, cef_shutdown, cef_string_t,
+    cef_render_process_handler_t, cef_resource_bundle_handler_t, cef_run_message_loop,
+    cef_scheme_registrar_t, cef_shutdown, cef_string_t,
 };
 
 use crate::{
     args::Args, browser_process_handler::BrowserProcessHandler, command_line::CommandLine,
-    rc::RcImpl, render_process_handler::RenderProcessHandler, settings::Settings,
-    string::CefString, ResultCode,
+    rc::RcImpl, render_process_handler::RenderProcessHandler,
+    resource_bundle_handler::ResourceBundleHandler, scheme::{SchemeOptions, SchemeRegistrar},
+    settings::Settings, string::CefString, ResultCode,
 };
 
 /// See [cef_app_t] for more documentation.
 pub trait App: Sized {
     type RenderProcessHandler: RenderProcessHandler;
     type BrowserProcessHandler: BrowserProcessHandler;
+    type ResourceBundleHandler: ResourceBundleHandler;
 
     fn on_before_command_line_processing(
         &self,
@@ -24,6 +27,27 @@ pub trait App: Sized {
     ) {
     }
 
+    /// Register custom schemes. This is called on the main thread of every process type, so the
+    /// same schemes must be registered with the same options in each of them.
+    fn on_register_custom_schemes(&self, _registrar: &mut SchemeRegistrar) {}
+
+    /// Custom schemes to add in every process before [App::on_register_custom_schemes] is
+    /// called, as pairs of a scheme name like `app` and its options. This is called in every
+    /// process, so it should only list the schemes and leave creating their handlers to
+    /// [App::register_scheme_handler_factories].
+    fn get_custom_schemes(&self) -> Vec<(String, SchemeOptions)> {
+        Vec::new()
+    }
+
+    /// Register the scheme handler factories of [App::get_custom_schemes], for example with
+    /// [StaticAssetHandler::register](crate::static_asset::StaticAssetHandler::register). This is
+    /// only called in the browser process by [initialize], once cef is initialized.
+    fn register_scheme_handler_factories(&self) {}
+
+    fn get_resource_bundle_handler(&self) -> Option<&Self::ResourceBundleHandler> {
+        None
+    }
+
     fn get_render_process_handler(&self) -> Option<&Self::RenderProcessHandler> {
         None
     }
@@ -36,6 +60,8 @@ pub trait App: Sized {
         let mut object: cef_app_t = unsafe { std::mem::zeroed() };
 
         object.on_before_command_line_processing = Some(on_before_command_line_processing::<Self>);
+        object.on_register_custom_schemes = Some(on_register_custom_schemes::<Self>);
+        object.get_resource_bundle_handler = Some(get_resource_bundle_handler::<Self>);
         object.get_render_process_handler = Some(get_render_process_handler::<Self>);
         object.get_browser_process_handler = Some(get_browser_process_handler::<Self>);
 
@@ -56,6 +82,31 @@ extern "C" fn on_before_command_line_processing<I: App>(
         .on_before_command_line_processing(process_type, cmd);
 }
 
+extern "C" fn on_register_custom_schemes<I: App>(
+    this: *mut cef_app_t,
+    registrar: *mut cef_scheme_registrar_t,
+) {
+    let app: &RcImpl<_, &I> = RcImpl::get(this);
+    let mut registrar = SchemeRegistrar::from_mut_ptr(registrar);
+
+    for (scheme_name, options) in app.interface.get_custom_schemes() {
+        registrar.add_custom_scheme(CefString::new(&scheme_name), options);
+    }
+    app.interface.on_register_custom_schemes(&mut registrar);
+}
+
+extern "C" fn get_resource_bundle_handler<I: App>(
+    this: *mut cef_app_t,
+) -> *mut cef_resource_bundle_handler_t {
+    let app: &RcImpl<_, &I> = RcImpl::get(this);
+    let res = app.interface.get_resource_bundle_handler();
+
+    match res {
+        Some(handler) => handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
 extern "C" fn get_render_process_handler<I: App>(
     this: *mut cef_app_t,
 ) -> *mut cef_render_process_handler_t {
@@ -83,6 +134,7 @@ extern "C" fn get_browser_process_handler<I: App>(
 impl App for () {
     type RenderProcessHandler = ();
     type BrowserProcessHandler = ();
+    type ResourceBundleHandler = ();
 }
 
 /// See [cef_execute_process] for more documentation.
@@ -96,6 +148,8 @@ pub fn execute_process<T: App>(args: Option<&Args>, app: Option<&T>) -> i32 {
 }
 
 /// See [cef_initialize] for more documentation.
+///
+/// [App::register_scheme_handler_factories] is called once cef is initialized.
 pub fn initialize<T: App>(
     args: Option<&Args>,
     settings: &Settings,
@@ -103,11 +157,14 @@ pub fn initialize<T: App>(
 ) -> Result<(), ResultCode> {
     let args = args.unwrap_or(&Args::default()).to_raw();
     let settings = settings.get_raw();
-    let app = app
+    let raw_app = app
         .map(|app| app.get_raw())
         .unwrap_or(std::ptr::null_mut());
 
-    if unsafe { cef_initialize(&args, &settings, app, std::ptr::null_mut()) } == 1 {
+    if unsafe { cef_initialize(&args, &settings, raw_app, std::ptr::null_mut()) } == 1 {
+        if let Some(app) = app {
+            app.register_scheme_handler_factories();
+        }
         Ok(())
     } else {
         Err(
@@ -1,4 +1,4 @@
-use std::{ffi::c_int, ptr::null_mut};
+use std::{ffi::c_int, path::Path, ptr::null_mut};
 
 use cef_sys::{
     cef_browser_host_create_browser, cef_browser_host_create_browser_sync, cef_browser_host_t,
@@ -6,7 +6,7 @@ use cef_sys::{
 };
 
 use crate::{
-    client::Client, frame::Frame, rc::RcImpl, render_utils::PaintElementType, string::CefString, window::WindowInfo, wrapper, State, View
+    client::Client, dialog_handler::RunFileDialogCallback, frame::Frame, rc::RcImpl, render_utils::PaintElementType, request_context::RequestContext, string::{CefString, CefStringList}, window::WindowInfo, wrapper, FileDialogMode, State, View
 };
 
 /// See [cef_browser_settings_t] for more documentation.
@@ -108,6 +108,38 @@ impl BrowserSettings {
             chrome_zoom_bubble: self.chrome_zoom_bubble,
         }
     }
+
+    /// Copy the settings into a raw [cef_browser_settings_t] provided by cef, like an
+    /// out-parameter. The strings are copied and owned by cef afterwards.
+    pub(crate) fn set_raw(&self, raw: &mut cef_browser_settings_t) {
+        raw.windowless_frame_rate = self.windowless_frame_rate as c_int;
+        unsafe { self.standard_font_family.set_raw(&mut raw.standard_font_family) };
+        unsafe { self.fixed_font_family.set_raw(&mut raw.fixed_font_family) };
+        unsafe { self.serif_font_family.set_raw(&mut raw.serif_font_family) };
+        unsafe { self.sans_serif_font_family.set_raw(&mut raw.sans_serif_font_family) };
+        unsafe { self.cursive_font_family.set_raw(&mut raw.cursive_font_family) };
+        unsafe { self.fantasy_font_family.set_raw(&mut raw.fantasy_font_family) };
+        raw.default_font_size = self.default_font_size as c_int;
+        raw.default_fixed_font_size = self.default_fixed_font_size as c_int;
+        raw.minimum_font_size = self.minimum_font_size as c_int;
+        raw.minimum_logical_font_size = self.minimum_logical_font_size as c_int;
+        unsafe { self.default_encoding.set_raw(&mut raw.default_encoding) };
+        raw.remote_fonts = self.remote_fonts;
+        raw.javascript = self.javascript;
+        raw.javascript_close_windows = self.javascript_close_windows;
+        raw.javascript_access_clipboard = self.javascript_access_clipboard;
+        raw.javascript_dom_paste = self.javascript_dom_paste;
+        raw.image_loading = self.image_loading;
+        raw.image_shrink_standalone_to_fit = self.image_shrink_standalone_to_fit;
+        raw.text_area_resize = self.text_area_resize;
+        raw.tab_to_links = self.tab_to_links;
+        raw.local_storage = self.local_storage;
+        raw.databases = self.databases;
+        raw.webgl = self.webgl;
+        raw.background_color = self.background_color;
+        raw.chrome_status_bubble = self.chrome_status_bubble;
+        raw.chrome_zoom_bubble = self.chrome_zoom_bubble;
+    }
 }
 
 impl From<&cef_browser_settings_t> for BrowserSettings {
@@ -145,13 +177,19 @@ impl From<&cef_browser_settings_t> for BrowserSettings {
 }
 
 /// See [cef_browser_host_create_browser] for more documentation.
+///
+/// The browser uses the global request context if `request_context` is `None`.
 pub fn create_browser<T: Client>(
     window_info: WindowInfo,
     client: Option<T>,
     url: CefString,
     settings: BrowserSettings,
+    request_context: Option<RequestContext>,
 ) -> i32 {
     let client = client.map(|c| c.into_raw()).unwrap_or(null_mut());
+    let request_context = request_context
+        .map(|c| unsafe { c.into_raw() })
+        .unwrap_or(null_mut());
 
     unsafe {
         cef_browser_host_create_browser(
@@ -160,7 +198,7 @@ pub fn create_browser<T: Client>(
             &url.get_raw(),
             &settings.into_raw(),
             null_mut(),
-            null_mut(),
+            request_context,
         )
     }
 }
@@ -233,6 +271,47 @@ impl BrowserHost {
             unsafe { f(self.0.get_raw(), type_) };
         }
     }
+
+    /// Download the file at `url` using [DownloadHandler](crate::download_handler::DownloadHandler).
+    pub fn start_download(&self, url: CefString) {
+        if let Some(f) = self.0.start_download {
+            unsafe { f(self.0.get_raw(), &url.get_raw()) };
+        }
+    }
+
+    /// Run a file chooser dialog. `callback` is called with the selected paths once the dialog
+    /// is dismissed. Only one dialog can be pending at a time. See
+    /// [DialogHandler::on_file_dialog](crate::dialog_handler::DialogHandler::on_file_dialog) for
+    /// the parameters, the handler is also called for this dialog.
+    ///
+    /// This must be called on the browser process UI thread.
+    pub fn run_file_dialog(
+        &self,
+        mode: FileDialogMode,
+        title: Option<CefString>,
+        default_file_path: Option<&Path>,
+        accept_filters: &[&str],
+        callback: impl RunFileDialogCallback,
+    ) {
+        if let Some(f) = self.0.run_file_dialog {
+            let title = title.unwrap_or_default();
+            let default_file_path = default_file_path
+                .map(|p| CefString::new(&p.to_string_lossy()))
+                .unwrap_or_default();
+            let mut accept_filters: CefStringList = accept_filters.iter().copied().collect();
+
+            unsafe {
+                f(
+                    self.0.get_raw(),
+                    mode,
+                    &title.get_raw(),
+                    &default_file_path.get_raw(),
+                    accept_filters.get_raw_mut(),
+                    callback.into_raw(),
+                )
+            };
+        }
+    }
 }
 
 wrapper!(
@@ -258,6 +337,14 @@ impl Browser {
         })
     }
 
+    /// Returns the globally unique identifier for this browser.
+    pub fn get_identifier(&self) -> i32 {
+        self.0
+            .get_identifier
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
     pub fn get_main_frame(&self) -> Option<Frame> {
         self.0.get_main_frame.and_then(|f| {
             let p = unsafe { f(self.0.get_raw()) };
@@ -270,14 +357,20 @@ impl Browser {
     }
 }
 
-/// See [cef_browser_host_create_browser] for more documentation.
+/// See [cef_browser_host_create_browser_sync] for more documentation.
+///
+/// The browser uses the global request context if `request_context` is `None`.
 pub fn create_browser_sync<T: Client>(
     window_info: WindowInfo,
     client: Option<T>,
     url: CefString,
     settings: BrowserSettings,
+    request_context: Option<RequestContext>,
 ) -> Browser {
     let client = client.map(|c| c.into_raw()).unwrap_or(null_mut());
+    let request_context = request_context
+        .map(|c| unsafe { c.into_raw() })
+        .unwrap_or(null_mut());
 
     unsafe {
         let ptr = cef_browser_host_create_browser_sync(
@@ -286,7 +379,7 @@ pub fn create_browser_sync<T: Client>(
             &url.get_raw(),
             &settings.into_raw(),
             null_mut(),
-            null_mut(),
+            request_context,
         );
 
         Browser::from_raw(ptr)
@@ -317,13 +410,19 @@ impl BrowserView {
 }
 
 /// See [cef_browser_view_create] for more documentation.
+///
+/// The browser uses the global request context if `request_context` is `None`.
 pub fn create_browser_view<T: Client>(
     client: Option<T>,
     url: CefString,
     settings: BrowserSettings,
+    request_context: Option<RequestContext>,
     // TODO delegate: *mut _cef_browser_view_delegate_t,
 ) -> BrowserView {
     let client = client.map(|c| c.into_raw()).unwrap_or(null_mut());
+    let request_context = request_context
+        .map(|c| unsafe { c.into_raw() })
+        .unwrap_or(null_mut());
 
     let view = unsafe {
         cef_browser_view_create(
@@ -331,7 +430,7 @@ pub fn create_browser_view<T: Client>(
             &url.get_raw(),
             &settings.into_raw(),
             null_mut(),
-            null_mut(),
+            request_context,
             null_mut(),
         )
     };
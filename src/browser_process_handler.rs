@@ -1,11 +1,24 @@
 use std::time::Duration;
 
-use cef_sys::{cef_browser_process_handler_t, cef_command_line_t};
+use cef_sys::{
+    cef_browser_process_handler_t, cef_command_line_t, cef_preference_registrar_t,
+    cef_preferences_type_t,
+};
 
-use crate::{rc::RcImpl, CommandLine};
+use crate::{preference::PreferenceRegistrar, rc::RcImpl, CommandLine, PreferencesType};
 
 /// See [cef_browser_process_handler_t] for more documentation.
 pub trait BrowserProcessHandler: Sized {
+    /// Register custom preferences before the global and request context preferences are
+    /// initialized. Global preferences are registered once at startup, request context
+    /// preferences each time a [RequestContext](crate::request_context::RequestContext) is
+    /// created.
+    fn on_register_custom_preferences(
+        &self,
+        _type_: PreferencesType,
+        _registrar: &mut PreferenceRegistrar,
+    ) {
+    }
     fn on_context_initialized(&self) {}
     fn on_before_child_process_launch(&self, _command_line: CommandLine) {}
     fn on_schedule_message_pump_work(&self, _delay: Duration) {}
@@ -13,6 +26,7 @@ pub trait BrowserProcessHandler: Sized {
     fn get_raw(&self) -> *mut cef_browser_process_handler_t {
         let mut object: cef_browser_process_handler_t = unsafe { std::mem::zeroed() };
 
+        object.on_register_custom_preferences = Some(on_register_custom_preferences::<Self>);
         object.on_context_initialized = Some(on_context_initialized::<Self>);
         object.on_schedule_message_pump_work = Some(on_schedule_message_pump_work::<Self>);
         object.on_before_child_process_launch = Some(on_before_child_process_launch::<Self>);
@@ -23,6 +37,19 @@ pub trait BrowserProcessHandler: Sized {
 
 impl BrowserProcessHandler for () {}
 
+extern "C" fn on_register_custom_preferences<H: BrowserProcessHandler>(
+    this: *mut cef_browser_process_handler_t,
+    type_: cef_preferences_type_t,
+    registrar: *mut cef_preference_registrar_t,
+) {
+    let handler: &RcImpl<_, &H> = RcImpl::get(this);
+    let mut registrar = PreferenceRegistrar::from_mut_ptr(registrar);
+
+    handler
+        .interface
+        .on_register_custom_preferences(type_, &mut registrar);
+}
+
 extern "C" fn on_context_initialized<H: BrowserProcessHandler>(
     this: *mut cef_browser_process_handler_t,
 ) {
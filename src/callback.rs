@@ -0,0 +1,11 @@
+use cef_sys::cef_callback_t;
+
+use crate::wrapper;
+
+wrapper!(
+    #[doc = "See [cef_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct Callback(cef_callback_t);
+    pub fn cont(&self);
+    pub fn cancel(&self);
+);
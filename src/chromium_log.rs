@@ -0,0 +1,542 @@
+//! Chromium log module
+//!
+//! Re-emit the internal log of cef and Chromium through `log` or `tracing`. This module is only
+//! available with the `log` or `tracing` feature.
+//!
+//! cef doesn't expose a log callback, every process appends to the file configured with
+//! [Settings::log_file](crate::Settings::log_file) instead. [ChromiumLogForwarder] tails that
+//! file on a background thread, parses each entry into a [ChromiumLogLine] and emits it with the
+//! target `cef::chromium`. The process id, thread id and source location are attached as fields
+//! when [Settings::log_items](crate::Settings::log_items) includes them. If both features are
+//! enabled, entries are emitted as `tracing` events only.
+//!
+//! ```no_run
+//! use cef::{chromium_log::ChromiumLogForwarder, Settings};
+//!
+//! let settings = Settings {
+//!     log_file: "cef.log".into(),
+//!     ..Default::default()
+//! };
+//! let forwarder = ChromiumLogForwarder::from_settings(&settings).unwrap();
+//! // Initialize cef and run the message loop, then:
+//! forwarder.stop();
+//! ```
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use bitflags::bitflags;
+
+use crate::{LogItems, LogSeverity, Settings};
+
+/// The target of the emitted records and events.
+pub const TARGET: &str = "cef::chromium";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+bitflags! {
+    /// The items prepended to each entry of the log, in this order. See [LogItems] for more
+    /// documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct LogItemFlags: u32 {
+        const PROCESS_ID = LogItems::LOG_ITEMS_FLAG_PROCESS_ID as u32;
+        const THREAD_ID = LogItems::LOG_ITEMS_FLAG_THREAD_ID as u32;
+        const TIME_STAMP = LogItems::LOG_ITEMS_FLAG_TIME_STAMP as u32;
+        const TICK_COUNT = LogItems::LOG_ITEMS_FLAG_TICK_COUNT as u32;
+    }
+}
+
+impl Default for LogItemFlags {
+    /// The items of [LogItems::LOG_ITEMS_DEFAULT].
+    fn default() -> Self {
+        Self::PROCESS_ID | Self::THREAD_ID | Self::TIME_STAMP
+    }
+}
+
+impl From<LogItems> for LogItemFlags {
+    fn from(items: LogItems) -> Self {
+        match items {
+            LogItems::LOG_ITEMS_DEFAULT => Self::default(),
+            LogItems::LOG_ITEMS_NONE => Self::empty(),
+            items => Self::from_bits_truncate(items as u32),
+        }
+    }
+}
+
+/// An entry of the Chromium log, like
+/// `[12345:12367:0419/101112.131415:WARNING:render_frame_host_impl.cc(123)] message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChromiumLogLine {
+    pub process_id: Option<u32>,
+    pub thread_id: Option<u32>,
+    /// The local time in the `MMDD/HHMMSS.mmm` format.
+    pub timestamp: Option<String>,
+    /// The time since boot in an unspecified unit.
+    pub tick_count: Option<u64>,
+    pub severity: LogSeverity,
+    /// The verbosity of `VERBOSE` entries, 0 otherwise.
+    pub verbose_level: u32,
+    pub file: String,
+    pub line: u32,
+    /// The message. Messages spanning multiple lines are joined with `\n`.
+    pub message: String,
+}
+
+impl ChromiumLogLine {
+    /// Parse the first line of a log entry whose header has the `items` configured with
+    /// [Settings::log_items](crate::Settings::log_items). Returns `None` if the line has no valid
+    /// header, in which case it continues the previous entry.
+    pub fn parse(line: &str, items: LogItemFlags) -> Option<Self> {
+        let (header, message) = line.strip_prefix('[')?.split_once("] ")?;
+
+        // Parse from the left since the file name may contain `:` on Windows.
+        let mut fields = header.splitn(items.iter().count() + 2, ':');
+        let mut field = |item: LogItemFlags| {
+            if items.contains(item) {
+                fields.next().map(Some)
+            } else {
+                Some(None)
+            }
+        };
+        let process_id = field(LogItemFlags::PROCESS_ID)?
+            .map(str::parse)
+            .transpose()
+            .ok()?;
+        let thread_id = field(LogItemFlags::THREAD_ID)?
+            .map(str::parse)
+            .transpose()
+            .ok()?;
+        let timestamp = field(LogItemFlags::TIME_STAMP)?;
+        if timestamp.is_some_and(|t| !t.contains('/')) {
+            return None;
+        }
+        let tick_count = field(LogItemFlags::TICK_COUNT)?
+            .map(str::parse)
+            .transpose()
+            .ok()?;
+        let (severity, verbose_level) = parse_severity(fields.next()?)?;
+        let (file, line) = fields.next()?.strip_suffix(')')?.rsplit_once('(')?;
+        let line = line.parse().ok()?;
+
+        Some(Self {
+            process_id,
+            thread_id,
+            timestamp: timestamp.map(str::to_string),
+            tick_count,
+            severity,
+            verbose_level,
+            file: file.to_string(),
+            line,
+            message: message.to_string(),
+        })
+    }
+
+    /// An `INFO` entry without header.
+    fn unparsed(message: &str) -> Self {
+        Self {
+            process_id: None,
+            thread_id: None,
+            timestamp: None,
+            tick_count: None,
+            severity: LogSeverity::LOGSEVERITY_INFO,
+            verbose_level: 0,
+            file: String::new(),
+            line: 0,
+            message: message.to_string(),
+        }
+    }
+
+    /// Emit the entry as a `log` record or a `tracing` event.
+    pub fn emit(&self) {
+        let pid = self.process_id.unwrap_or_default();
+        let tid = self.thread_id.unwrap_or_default();
+        let (file, line, message) = (self.file.as_str(), self.line, self.message.as_str());
+
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::{debug, error, info, trace, warn};
+
+            match self.severity {
+                LogSeverity::LOGSEVERITY_VERBOSE if self.verbose_level > 1 => {
+                    trace!(target: TARGET, pid, tid, file, line, "{message}")
+                }
+                LogSeverity::LOGSEVERITY_VERBOSE => {
+                    debug!(target: TARGET, pid, tid, file, line, "{message}")
+                }
+                LogSeverity::LOGSEVERITY_WARNING => {
+                    warn!(target: TARGET, pid, tid, file, line, "{message}")
+                }
+                LogSeverity::LOGSEVERITY_ERROR | LogSeverity::LOGSEVERITY_FATAL => {
+                    error!(target: TARGET, pid, tid, file, line, "{message}")
+                }
+                _ => info!(target: TARGET, pid, tid, file, line, "{message}"),
+            }
+        }
+
+        #[cfg(all(feature = "log", not(feature = "tracing")))]
+        {
+            use log::{kv::Value, Level, Record};
+
+            let level = match self.severity {
+                LogSeverity::LOGSEVERITY_VERBOSE if self.verbose_level > 1 => Level::Trace,
+                LogSeverity::LOGSEVERITY_VERBOSE => Level::Debug,
+                LogSeverity::LOGSEVERITY_WARNING => Level::Warn,
+                LogSeverity::LOGSEVERITY_ERROR | LogSeverity::LOGSEVERITY_FATAL => Level::Error,
+                _ => Level::Info,
+            };
+            let key_values = [("pid", Value::from(pid)), ("tid", Value::from(tid))];
+
+            log::logger().log(
+                &Record::builder()
+                    .args(format_args!("{message}"))
+                    .level(level)
+                    .target(TARGET)
+                    .file(Some(file))
+                    .line(Some(line))
+                    .key_values(&key_values)
+                    .build(),
+            );
+        }
+    }
+}
+
+fn parse_severity(severity: &str) -> Option<(LogSeverity, u32)> {
+    let severity = match severity {
+        "INFO" => (LogSeverity::LOGSEVERITY_INFO, 0),
+        "WARNING" => (LogSeverity::LOGSEVERITY_WARNING, 0),
+        "ERROR" => (LogSeverity::LOGSEVERITY_ERROR, 0),
+        "FATAL" => (LogSeverity::LOGSEVERITY_FATAL, 0),
+        _ => (
+            LogSeverity::LOGSEVERITY_VERBOSE,
+            severity.strip_prefix("VERBOSE")?.parse().ok()?,
+        ),
+    };
+    Some(severity)
+}
+
+/// Tail the cef log file and emit every entry through `log` or `tracing`. The forwarder stops
+/// when it is dropped.
+#[derive(Debug)]
+pub struct ChromiumLogForwarder {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ChromiumLogForwarder {
+    /// Forward the entries appended to the file at `path` from now on, whose headers have the
+    /// `items` configured with [Settings::log_items](crate::Settings::log_items). The file is
+    /// created if it doesn't exist yet.
+    pub fn start(path: impl AsRef<Path>, items: LogItemFlags) -> io::Result<Self> {
+        let mut file = File::options()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(path)?;
+        let position = file.seek(SeekFrom::End(0))?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let thread = thread::Builder::new()
+            .name("cef-log-forwarder".into())
+            .spawn({
+                let stop = stop.clone();
+                move || tail(file, position, items, &stop)
+            })?;
+
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Forward the entries of the log file used with `settings`. If
+    /// [Settings::log_file](crate::Settings::log_file) is empty, cef writes to `debug.log` in the
+    /// directory of the executable.
+    pub fn from_settings(settings: &Settings) -> io::Result<Self> {
+        Self::start(log_file_path(settings)?, settings.log_items.into())
+    }
+
+    /// Stop forwarding after the entries written so far have been emitted.
+    pub fn stop(self) {}
+}
+
+impl Drop for ChromiumLogForwarder {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn log_file_path(settings: &Settings) -> io::Result<PathBuf> {
+    let log_file = settings.log_file.to_string();
+    if !log_file.is_empty() {
+        return Ok(log_file.into());
+    }
+
+    let exe = std::env::current_exe()?;
+    let dir = exe.parent().unwrap_or(Path::new("."));
+    Ok(dir.join("debug.log"))
+}
+
+/// Groups the lines of the log into entries.
+#[derive(Debug)]
+struct EntryReader {
+    items: LogItemFlags,
+    pending: Option<ChromiumLogLine>,
+}
+
+impl EntryReader {
+    fn new(items: LogItemFlags) -> Self {
+        Self {
+            items,
+            pending: None,
+        }
+    }
+
+    /// Add a line without its line break. Returns the entry it completes, if any.
+    fn push(&mut self, line: &str) -> Option<ChromiumLogLine> {
+        match (ChromiumLogLine::parse(line, self.items), &mut self.pending) {
+            (Some(entry), _) => self.pending.replace(entry),
+            (None, Some(entry)) => {
+                entry.message.push('\n');
+                entry.message.push_str(line);
+                None
+            }
+            // Output without a header, like messages printed before logging is set up.
+            (None, None) => Some(ChromiumLogLine::unparsed(line)),
+        }
+    }
+
+    /// Returns the pending entry, once no more lines can continue it.
+    fn finish(&mut self) -> Option<ChromiumLogLine> {
+        self.pending.take()
+    }
+}
+
+fn tail(file: File, mut position: u64, items: LogItemFlags, stop: &AtomicBool) {
+    let mut reader = BufReader::new(file);
+    let mut buffer = Vec::new();
+    let mut entries = EntryReader::new(items);
+
+    loop {
+        let stopping = stop.load(Ordering::Relaxed);
+
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(read) if read > 0 && buffer.ends_with(b"\n") => {
+                position += read as u64;
+                let line = String::from_utf8_lossy(&buffer);
+                let line = line.trim_end_matches(['\r', '\n']);
+                if let Some(entry) = entries.push(line) {
+                    entry.emit();
+                }
+                buffer.clear();
+                continue;
+            }
+            // A partial line stays in the buffer until the rest of it is written.
+            Ok(read) => position += read as u64,
+            Err(_) => buffer.clear(),
+        }
+
+        // Nothing left to read, so the pending entry is complete.
+        if let Some(entry) = entries.finish() {
+            entry.emit();
+        }
+        if stopping {
+            break;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+
+        // Start over if the file has been truncated.
+        let truncated = reader
+            .get_ref()
+            .metadata()
+            .map(|m| m.len() < position)
+            .unwrap_or(false);
+        if truncated && reader.seek(SeekFrom::Start(0)).is_ok() {
+            position = 0;
+            buffer.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(items: LogItemFlags) -> String {
+        let mut header = String::from("[");
+        for (item, value) in [
+            (LogItemFlags::PROCESS_ID, "12345:"),
+            (LogItemFlags::THREAD_ID, "67:"),
+            (LogItemFlags::TIME_STAMP, "0419/101112.131415:"),
+            (LogItemFlags::TICK_COUNT, "987654321:"),
+        ] {
+            if items.contains(item) {
+                header.push_str(value);
+            }
+        }
+        header
+    }
+
+    #[test]
+    fn parse_every_log_items_combination() {
+        for bits in 0..16 {
+            let items = LogItemFlags::from_bits_truncate(bits << 1);
+            let line = format!("{}WARNING:frame.cc(42)] message", header(items));
+            let entry = ChromiumLogLine::parse(&line, items)
+                .unwrap_or_else(|| panic!("{line} is not parsed with {items:?}"));
+
+            assert_eq!(
+                entry.process_id,
+                items.contains(LogItemFlags::PROCESS_ID).then_some(12345),
+                "{line}"
+            );
+            assert_eq!(
+                entry.thread_id,
+                items.contains(LogItemFlags::THREAD_ID).then_some(67),
+                "{line}"
+            );
+            assert_eq!(
+                entry.timestamp.as_deref(),
+                items
+                    .contains(LogItemFlags::TIME_STAMP)
+                    .then_some("0419/101112.131415"),
+                "{line}"
+            );
+            assert_eq!(
+                entry.tick_count,
+                items
+                    .contains(LogItemFlags::TICK_COUNT)
+                    .then_some(987654321),
+                "{line}"
+            );
+            assert_eq!(entry.severity, LogSeverity::LOGSEVERITY_WARNING);
+            assert_eq!(entry.file, "frame.cc");
+            assert_eq!(entry.line, 42);
+            assert_eq!(entry.message, "message");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_headers_of_other_log_items() {
+        let line = "[12345:987654321:INFO:frame.cc(42)] message";
+        let items = LogItemFlags::PROCESS_ID | LogItemFlags::TICK_COUNT;
+        let entry = ChromiumLogLine::parse(line, items).unwrap();
+        assert_eq!(entry.process_id, Some(12345));
+        assert_eq!(entry.thread_id, None);
+        assert_eq!(entry.tick_count, Some(987654321));
+
+        assert_eq!(ChromiumLogLine::parse(line, LogItemFlags::default()), None);
+        assert_eq!(
+            ChromiumLogLine::parse("[0419/101112.131415:INFO:frame.cc(42)] m", items),
+            None
+        );
+        assert_eq!(
+            ChromiumLogLine::parse("[12:34:INFO:frame.cc(42)] m", LogItemFlags::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn log_items_conversion() {
+        assert_eq!(
+            LogItemFlags::from(LogItems::LOG_ITEMS_DEFAULT),
+            LogItemFlags::PROCESS_ID | LogItemFlags::THREAD_ID | LogItemFlags::TIME_STAMP
+        );
+        assert_eq!(
+            LogItemFlags::from(LogItems::LOG_ITEMS_NONE),
+            LogItemFlags::empty()
+        );
+        assert_eq!(
+            LogItemFlags::from(LogItems::LOG_ITEMS_FLAG_TICK_COUNT),
+            LogItemFlags::TICK_COUNT
+        );
+    }
+
+    #[test]
+    fn parse_severities() {
+        let parse = |severity: &str| {
+            let line = format!("[{severity}:frame.cc(1)] m");
+            ChromiumLogLine::parse(&line, LogItemFlags::empty())
+                .map(|entry| (entry.severity, entry.verbose_level))
+        };
+
+        assert_eq!(parse("INFO"), Some((LogSeverity::LOGSEVERITY_INFO, 0)));
+        assert_eq!(parse("ERROR"), Some((LogSeverity::LOGSEVERITY_ERROR, 0)));
+        assert_eq!(parse("FATAL"), Some((LogSeverity::LOGSEVERITY_FATAL, 0)));
+        assert_eq!(
+            parse("VERBOSE1"),
+            Some((LogSeverity::LOGSEVERITY_VERBOSE, 1))
+        );
+        assert_eq!(
+            parse("VERBOSE12"),
+            Some((LogSeverity::LOGSEVERITY_VERBOSE, 12))
+        );
+        assert_eq!(parse("VERBOSE"), None);
+        assert_eq!(parse("VERBOSEx"), None);
+        assert_eq!(parse("DEBUG"), None);
+    }
+
+    #[test]
+    fn parse_windows_paths() {
+        let line = "[1234:5678:0419/101112.131:ERROR:C:\\src\\cef\\frame.cc(42)] failed: yes";
+        let entry = ChromiumLogLine::parse(line, LogItemFlags::default()).unwrap();
+
+        assert_eq!(entry.process_id, Some(1234));
+        assert_eq!(entry.thread_id, Some(5678));
+        assert_eq!(entry.severity, LogSeverity::LOGSEVERITY_ERROR);
+        assert_eq!(entry.file, "C:\\src\\cef\\frame.cc");
+        assert_eq!(entry.line, 42);
+        assert_eq!(entry.message, "failed: yes");
+
+        let line = "[INFO:D:\\a(b)\\c.cc(7)] m";
+        let entry = ChromiumLogLine::parse(line, LogItemFlags::empty()).unwrap();
+        assert_eq!(entry.file, "D:\\a(b)\\c.cc");
+        assert_eq!(entry.line, 7);
+    }
+
+    #[test]
+    fn parse_rejects_continuation_lines() {
+        let items = LogItemFlags::default();
+
+        assert_eq!(ChromiumLogLine::parse("  at frame.cc(42)", items), None);
+        assert_eq!(ChromiumLogLine::parse("[1, 2] is an array", items), None);
+        assert_eq!(
+            ChromiumLogLine::parse("[1:2:0419/1:INFO:a.cc] m", items),
+            None
+        );
+        assert_eq!(ChromiumLogLine::parse("", items), None);
+    }
+
+    #[test]
+    fn entries_join_continuation_lines() {
+        let mut entries = EntryReader::new(LogItemFlags::empty());
+
+        let orphan = entries.push("output before logging").unwrap();
+        assert_eq!(orphan.message, "output before logging");
+        assert_eq!(orphan.severity, LogSeverity::LOGSEVERITY_INFO);
+
+        assert_eq!(entries.push("[INFO:a.cc(1)] first"), None);
+        assert_eq!(entries.push("second"), None);
+        assert_eq!(entries.push(""), None);
+        let entry = entries.push("[WARNING:b.cc(2)] next").unwrap();
+        assert_eq!(entry.file, "a.cc");
+        assert_eq!(entry.message, "first\nsecond\n");
+
+        let entry = entries.finish().unwrap();
+        assert_eq!(entry.file, "b.cc");
+        assert_eq!(entry.message, "next");
+        assert_eq!(entries.finish(), None);
+    }
+}
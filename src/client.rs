@@ -1,14 +1,23 @@
 use std::ptr::null_mut;
 
-use cef_sys::{cef_browser_t, cef_client_t, cef_frame_t, cef_life_span_handler_t, cef_load_handler_t, cef_process_id_t, cef_process_message_t, cef_render_handler_t};
+use cef_sys::{cef_browser_t, cef_client_t, cef_context_menu_handler_t, cef_dialog_handler_t, cef_display_handler_t, cef_download_handler_t, cef_focus_handler_t, cef_frame_t, cef_jsdialog_handler_t, cef_keyboard_handler_t, cef_life_span_handler_t, cef_load_handler_t, cef_permission_handler_t, cef_process_id_t, cef_process_message_t, cef_render_handler_t, cef_request_handler_t};
 
-use crate::{frame::Frame, life_span_handler::LifeSpanHandler, load_handler::LoadHandler, process::{ProcessId, ProcessMessage}, rc::RcImpl, render_handler::RenderHandler, Browser};
+use crate::{context_menu_handler::ContextMenuHandler, dialog_handler::DialogHandler, display_handler::DisplayHandler, download_handler::DownloadHandler, focus_handler::FocusHandler, frame::Frame, jsdialog_handler::JsDialogHandler, keyboard_handler::KeyboardHandler, life_span_handler::LifeSpanHandler, load_handler::LoadHandler, permission_handler::PermissionHandler, process::{ProcessId, ProcessMessage}, rc::RcImpl, render_handler::RenderHandler, request_handler::RequestHandler, Browser};
 
 /// See [cef_client_t] for more documentation.
 pub trait Client: Sized {
     type RenderHandler: RenderHandler;
     type LoadHandler: LoadHandler;
     type LifeSpanHandler: LifeSpanHandler;
+    type DownloadHandler: DownloadHandler;
+    type RequestHandler: RequestHandler;
+    type DisplayHandler: DisplayHandler;
+    type KeyboardHandler: KeyboardHandler;
+    type FocusHandler: FocusHandler;
+    type ContextMenuHandler: ContextMenuHandler;
+    type JsDialogHandler: JsDialogHandler;
+    type DialogHandler: DialogHandler;
+    type PermissionHandler: PermissionHandler;
 
     fn get_render_handler(&self) -> Option<&Self::RenderHandler> {
         None
@@ -22,6 +31,42 @@ pub trait Client: Sized {
         None
     }
 
+    fn get_download_handler(&self) -> Option<&Self::DownloadHandler> {
+        None
+    }
+
+    fn get_request_handler(&self) -> Option<&Self::RequestHandler> {
+        None
+    }
+
+    fn get_display_handler(&self) -> Option<&Self::DisplayHandler> {
+        None
+    }
+
+    fn get_keyboard_handler(&self) -> Option<&Self::KeyboardHandler> {
+        None
+    }
+
+    fn get_focus_handler(&self) -> Option<&Self::FocusHandler> {
+        None
+    }
+
+    fn get_context_menu_handler(&self) -> Option<&Self::ContextMenuHandler> {
+        None
+    }
+
+    fn get_jsdialog_handler(&self) -> Option<&Self::JsDialogHandler> {
+        None
+    }
+
+    fn get_dialog_handler(&self) -> Option<&Self::DialogHandler> {
+        None
+    }
+
+    fn get_permission_handler(&self) -> Option<&Self::PermissionHandler> {
+        None
+    }
+
     fn on_process_message_received(
         &self,
         _browser: Browser,
@@ -39,6 +84,15 @@ pub trait Client: Sized {
         object.get_render_handler = Some(get_render_handler::<Self>);
         object.get_load_handler = Some(get_load_handler::<Self>);
         object.get_life_span_handler = Some(get_life_span_handler::<Self>);
+        object.get_download_handler = Some(get_download_handler::<Self>);
+        object.get_request_handler = Some(get_request_handler::<Self>);
+        object.get_display_handler = Some(get_display_handler::<Self>);
+        object.get_keyboard_handler = Some(get_keyboard_handler::<Self>);
+        object.get_focus_handler = Some(get_focus_handler::<Self>);
+        object.get_context_menu_handler = Some(get_context_menu_handler::<Self>);
+        object.get_jsdialog_handler = Some(get_jsdialog_handler::<Self>);
+        object.get_dialog_handler = Some(get_dialog_handler::<Self>);
+        object.get_permission_handler = Some(get_permission_handler::<Self>);
         object.on_process_message_received = Some(on_process_message_received::<Self>);
 
         RcImpl::new(object, self) as *mut _
@@ -75,6 +129,96 @@ extern "C" fn get_life_span_handler<C: Client>(this: *mut cef_client_t) -> *mut
     }
 }
 
+extern "C" fn get_download_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_download_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_download_handler();
+
+    match res {
+        Some(download_handler) => download_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_request_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_request_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_request_handler();
+
+    match res {
+        Some(request_handler) => request_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_display_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_display_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_display_handler();
+
+    match res {
+        Some(display_handler) => display_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_keyboard_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_keyboard_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_keyboard_handler();
+
+    match res {
+        Some(keyboard_handler) => keyboard_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_focus_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_focus_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_focus_handler();
+
+    match res {
+        Some(focus_handler) => focus_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_context_menu_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_context_menu_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_context_menu_handler();
+
+    match res {
+        Some(context_menu_handler) => context_menu_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_jsdialog_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_jsdialog_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_jsdialog_handler();
+
+    match res {
+        Some(jsdialog_handler) => jsdialog_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_dialog_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_dialog_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_dialog_handler();
+
+    match res {
+        Some(dialog_handler) => dialog_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_permission_handler<C: Client>(this: *mut cef_client_t) -> *mut cef_permission_handler_t {
+    let client: &mut RcImpl<_, C> = RcImpl::get(this);
+    let res = client.interface.get_permission_handler();
+
+    match res {
+        Some(permission_handler) => permission_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
 extern "C" fn on_process_message_received<C: Client>(
     this: *mut cef_client_t,
     browser: *mut cef_browser_t,
@@ -0,0 +1,230 @@
+//! Console log module
+//!
+//! Forward the console output of pages to the `log` or `tracing` ecosystem. This module is only
+//! available with the `log` or `tracing` feature.
+//!
+//! [ConsoleLogger] is a [DisplayHandler] that emits every console message as a `log` record or
+//! a `tracing` event with the target `cef::console`. The source URL and line are attached as
+//! fields and, with `tracing`, the event is recorded inside a `cef_browser` span carrying the
+//! browser identifier. With `log`, the identifier is a `browser_id` key-value instead. If both
+//! features are enabled, messages are emitted as `tracing` events only.
+//!
+//! Console levels are mapped as follows:
+//!
+//! | [LogSeverity]                    | `log`   | `tracing` |
+//! |----------------------------------|---------|-----------|
+//! | `LOGSEVERITY_VERBOSE`            | `Debug` | `DEBUG`   |
+//! | `LOGSEVERITY_DEFAULT`, `_INFO`   | `Info`  | `INFO`    |
+//! | `LOGSEVERITY_WARNING`            | `Warn`  | `WARN`    |
+//! | `LOGSEVERITY_ERROR`, `_FATAL`    | `Error` | `ERROR`   |
+//!
+//! Other display events are forwarded to an optional inner handler:
+//!
+//! ```no_run
+//! use cef::{client::Client, console_log::ConsoleLogger};
+//!
+//! struct MyClient {
+//!     display_handler: ConsoleLogger,
+//! }
+//!
+//! impl Client for MyClient {
+//!     # type RenderHandler = ();
+//!     # type LoadHandler = ();
+//!     # type LifeSpanHandler = ();
+//!     # type DownloadHandler = ();
+//!     # type RequestHandler = ();
+//!     type DisplayHandler = ConsoleLogger;
+//!     # type PermissionHandler = ();
+//!     # type DialogHandler = ();
+//!     # type JsDialogHandler = ();
+//!     # type ContextMenuHandler = ();
+//!     # type FocusHandler = ();
+//!     # type KeyboardHandler = ();
+//!     // ...
+//!
+//!     fn get_display_handler(&self) -> Option<&Self::DisplayHandler> {
+//!         Some(&self.display_handler)
+//!     }
+//! }
+//! ```
+
+use std::os::raw::c_ulong;
+
+use crate::{
+    display_handler::{CursorInfo, DisplayHandler},
+    frame::Frame,
+    render_utils::CefSize,
+    string::CefString,
+    Browser, CursorType, LogSeverity,
+};
+
+/// The target of the emitted records and events.
+pub const TARGET: &str = "cef::console";
+
+/// A [DisplayHandler] forwarding console messages to `log` or `tracing`, and every other event
+/// to the inner handler `H`.
+#[derive(Debug, Clone, Default)]
+pub struct ConsoleLogger<H = ()> {
+    inner: H,
+    suppress_console: bool,
+}
+
+impl ConsoleLogger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<H: DisplayHandler> ConsoleLogger<H> {
+    /// Forward every other display event to `inner`.
+    pub fn with_inner(inner: H) -> Self {
+        Self {
+            inner,
+            suppress_console: false,
+        }
+    }
+
+    /// If `suppress` is `true` messages are not written to the console by cef anymore once they
+    /// have been logged.
+    pub fn suppress_console(mut self, suppress: bool) -> Self {
+        self.suppress_console = suppress;
+        self
+    }
+
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+}
+
+/// Emit a console message as a `log` record or a `tracing` event. This can be called from an
+/// existing [DisplayHandler::on_console_message] implementation. Messages with
+/// `LOGSEVERITY_DISABLE` are dropped.
+pub fn log_console_message(
+    browser_id: i32,
+    level: LogSeverity,
+    message: &str,
+    source: &str,
+    line: i32,
+) {
+    #[cfg(feature = "tracing")]
+    {
+        use tracing::{debug, error, info, info_span, warn};
+
+        let span = info_span!(target: TARGET, "cef_browser", browser_id);
+        let _enter = span.enter();
+        match level {
+            LogSeverity::LOGSEVERITY_VERBOSE => debug!(target: TARGET, source, line, "{message}"),
+            LogSeverity::LOGSEVERITY_DEFAULT | LogSeverity::LOGSEVERITY_INFO => {
+                info!(target: TARGET, source, line, "{message}")
+            }
+            LogSeverity::LOGSEVERITY_WARNING => warn!(target: TARGET, source, line, "{message}"),
+            LogSeverity::LOGSEVERITY_ERROR | LogSeverity::LOGSEVERITY_FATAL => {
+                error!(target: TARGET, source, line, "{message}")
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(all(feature = "log", not(feature = "tracing")))]
+    {
+        use log::{kv::Value, Level, Record};
+
+        let level = match level {
+            LogSeverity::LOGSEVERITY_VERBOSE => Level::Debug,
+            LogSeverity::LOGSEVERITY_DEFAULT | LogSeverity::LOGSEVERITY_INFO => Level::Info,
+            LogSeverity::LOGSEVERITY_WARNING => Level::Warn,
+            LogSeverity::LOGSEVERITY_ERROR | LogSeverity::LOGSEVERITY_FATAL => Level::Error,
+            _ => return,
+        };
+        let key_values = [
+            ("browser_id", Value::from(browser_id)),
+            ("source", Value::from(source)),
+            ("line", Value::from(line)),
+        ];
+
+        log::logger().log(
+            &Record::builder()
+                .args(format_args!("{message}"))
+                .level(level)
+                .target(TARGET)
+                .file(Some(source))
+                .line(u32::try_from(line).ok())
+                .key_values(&key_values)
+                .build(),
+        );
+    }
+}
+
+impl<H: DisplayHandler> DisplayHandler for ConsoleLogger<H> {
+    fn on_console_message(
+        &self,
+        browser: Browser,
+        level: LogSeverity,
+        message: CefString,
+        source: CefString,
+        line: i32,
+    ) -> bool {
+        log_console_message(
+            browser.get_identifier(),
+            level,
+            &message.to_string(),
+            &source.to_string(),
+            line,
+        );
+
+        self.suppress_console
+    }
+
+    fn on_address_change(&self, browser: Browser, frame: Frame, url: CefString) {
+        self.inner.on_address_change(browser, frame, url)
+    }
+
+    fn on_title_change(&self, browser: Browser, title: CefString) {
+        self.inner.on_title_change(browser, title)
+    }
+
+    fn on_favicon_urlchange(&self, browser: Browser, icon_urls: Vec<String>) {
+        self.inner.on_favicon_urlchange(browser, icon_urls)
+    }
+
+    fn on_fullscreen_mode_change(&self, browser: Browser, fullscreen: bool) {
+        self.inner.on_fullscreen_mode_change(browser, fullscreen)
+    }
+
+    fn on_tooltip(&self, browser: Browser, text: &mut CefString) -> bool {
+        self.inner.on_tooltip(browser, text)
+    }
+
+    fn on_status_message(&self, browser: Browser, value: CefString) {
+        self.inner.on_status_message(browser, value)
+    }
+
+    fn on_auto_resize(&self, browser: Browser, new_size: CefSize) -> bool {
+        self.inner.on_auto_resize(browser, new_size)
+    }
+
+    fn on_loading_progress_change(&self, browser: Browser, progress: f64) {
+        self.inner.on_loading_progress_change(browser, progress)
+    }
+
+    fn on_cursor_change(
+        &self,
+        browser: Browser,
+        cursor: c_ulong,
+        type_: CursorType,
+        custom_cursor_info: Option<CursorInfo>,
+    ) -> bool {
+        self.inner
+            .on_cursor_change(browser, cursor, type_, custom_cursor_info)
+    }
+
+    fn on_media_access_change(
+        &self,
+        browser: Browser,
+        has_video_access: bool,
+        has_audio_access: bool,
+    ) {
+        self.inner
+            .on_media_access_change(browser, has_video_access, has_audio_access)
+    }
+}
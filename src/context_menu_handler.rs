@@ -0,0 +1,499 @@
+use bitflags::bitflags;
+use cef_sys::{
+    cef_browser_t, cef_context_menu_edit_state_flags_t, cef_context_menu_handler_t,
+    cef_context_menu_media_state_flags_t, cef_context_menu_params_t, cef_context_menu_type_flags_t,
+    cef_event_flags_t, cef_frame_t, cef_menu_model_t, cef_point_t,
+    cef_quick_menu_edit_state_flags_t, cef_run_context_menu_callback_t,
+    cef_run_quick_menu_callback_t, cef_size_t,
+};
+
+use crate::{
+    frame::Frame,
+    keyboard_handler::EventFlags,
+    menu_model::MenuModel,
+    rc::RcImpl,
+    render_utils::{CefPoint, CefSize},
+    string::{parse_string_list, CefString, CefStringError, CefStringList},
+    wrapper, Browser, ContextMenuMediaType,
+};
+
+bitflags! {
+    /// The kind of node the context menu was opened on. See [cef_context_menu_type_flags_t] for
+    /// more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ContextMenuTypeFlags: u32 {
+        /// No node is selected.
+        const PAGE = cef_context_menu_type_flags_t::CM_TYPEFLAG_PAGE.0;
+        /// A subframe page is selected.
+        const FRAME = cef_context_menu_type_flags_t::CM_TYPEFLAG_FRAME.0;
+        const LINK = cef_context_menu_type_flags_t::CM_TYPEFLAG_LINK.0;
+        /// An image, video or audio element is selected.
+        const MEDIA = cef_context_menu_type_flags_t::CM_TYPEFLAG_MEDIA.0;
+        /// There is a text selection.
+        const SELECTION = cef_context_menu_type_flags_t::CM_TYPEFLAG_SELECTION.0;
+        const EDITABLE = cef_context_menu_type_flags_t::CM_TYPEFLAG_EDITABLE.0;
+    }
+
+    /// The state of the media element the context menu was opened on. See
+    /// [cef_context_menu_media_state_flags_t] for more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MediaStateFlags: u32 {
+        const IN_ERROR = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_IN_ERROR.0;
+        const PAUSED = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_PAUSED.0;
+        const MUTED = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_MUTED.0;
+        const LOOP = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_LOOP.0;
+        const CAN_SAVE = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_SAVE.0;
+        const HAS_AUDIO = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_HAS_AUDIO.0;
+        const CAN_TOGGLE_CONTROLS = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_TOGGLE_CONTROLS.0;
+        const CONTROLS = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CONTROLS.0;
+        const CAN_PRINT = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_PRINT.0;
+        const CAN_ROTATE = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_ROTATE.0;
+        const CAN_PICTURE_IN_PICTURE = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_PICTURE_IN_PICTURE.0;
+        const PICTURE_IN_PICTURE = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_PICTURE_IN_PICTURE.0;
+        const CAN_LOOP = cef_context_menu_media_state_flags_t::CM_MEDIAFLAG_CAN_LOOP.0;
+    }
+
+    /// The editing actions available in the context menu. See
+    /// [cef_context_menu_edit_state_flags_t] for more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EditStateFlags: u32 {
+        const CAN_UNDO = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_UNDO.0;
+        const CAN_REDO = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_REDO.0;
+        const CAN_CUT = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_CUT.0;
+        const CAN_COPY = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_COPY.0;
+        const CAN_PASTE = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_PASTE.0;
+        const CAN_DELETE = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_DELETE.0;
+        const CAN_SELECT_ALL = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_SELECT_ALL.0;
+        const CAN_TRANSLATE = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_TRANSLATE.0;
+        const CAN_EDIT_RICHLY = cef_context_menu_edit_state_flags_t::CM_EDITFLAG_CAN_EDIT_RICHLY.0;
+    }
+
+    /// The editing actions available in the quick menu. See [cef_quick_menu_edit_state_flags_t]
+    /// for more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct QuickMenuEditStateFlags: u32 {
+        const CAN_ELLIPSIS = cef_quick_menu_edit_state_flags_t::QM_EDITFLAG_CAN_ELLIPSIS.0;
+        const CAN_CUT = cef_quick_menu_edit_state_flags_t::QM_EDITFLAG_CAN_CUT.0;
+        const CAN_COPY = cef_quick_menu_edit_state_flags_t::QM_EDITFLAG_CAN_COPY.0;
+        const CAN_PASTE = cef_quick_menu_edit_state_flags_t::QM_EDITFLAG_CAN_PASTE.0;
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_context_menu_params_t] for more documentation."]
+    #[doc = ""]
+    #[doc = "The parameters are only valid during the handler call they are passed to."]
+    #[derive(Debug, Clone)]
+    pub struct ContextMenuParams(cef_context_menu_params_t);
+);
+
+impl ContextMenuParams {
+    /// Returns the X coordinate of the mouse where the menu was opened, relative to the
+    /// browser view.
+    pub fn get_xcoord(&self) -> i32 {
+        self.0
+            .get_xcoord
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    /// Returns the Y coordinate of the mouse where the menu was opened, relative to the
+    /// browser view.
+    pub fn get_ycoord(&self) -> i32 {
+        self.0
+            .get_ycoord
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_type_flags(&self) -> ContextMenuTypeFlags {
+        self.0
+            .get_type_flags
+            .map(|f| ContextMenuTypeFlags::from_bits_truncate(unsafe { f(self.0.get_raw()) }.0))
+            .unwrap_or_default()
+    }
+
+    /// Returns the URL of the link, if any, after it was sanitized.
+    pub fn get_link_url(&self) -> Result<CefString, CefStringError> {
+        let get_link_url = self.0.get_link_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_link_url(self.0.get_raw())) }
+    }
+
+    pub fn get_unfiltered_link_url(&self) -> Result<CefString, CefStringError> {
+        let get_unfiltered_link_url = self.0.get_unfiltered_link_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_unfiltered_link_url(self.0.get_raw())) }
+    }
+
+    /// Returns the source URL of the image, video or audio element, if any.
+    pub fn get_source_url(&self) -> Result<CefString, CefStringError> {
+        let get_source_url = self.0.get_source_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_source_url(self.0.get_raw())) }
+    }
+
+    /// Returns `true` if the menu was opened on an image that has non-empty contents.
+    pub fn has_image_contents(&self) -> bool {
+        self.0
+            .has_image_contents
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the title or alt text of the element.
+    pub fn get_title_text(&self) -> Result<CefString, CefStringError> {
+        let get_title_text = self.0.get_title_text.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_title_text(self.0.get_raw())) }
+    }
+
+    /// Returns the URL of the top level page.
+    pub fn get_page_url(&self) -> Result<CefString, CefStringError> {
+        let get_page_url = self.0.get_page_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_page_url(self.0.get_raw())) }
+    }
+
+    /// Returns the URL of the subframe the menu was opened in.
+    pub fn get_frame_url(&self) -> Result<CefString, CefStringError> {
+        let get_frame_url = self.0.get_frame_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_frame_url(self.0.get_raw())) }
+    }
+
+    pub fn get_frame_charset(&self) -> Result<CefString, CefStringError> {
+        let get_frame_charset = self.0.get_frame_charset.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_frame_charset(self.0.get_raw())) }
+    }
+
+    pub fn get_media_type(&self) -> ContextMenuMediaType {
+        self.0
+            .get_media_type
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ContextMenuMediaType::CM_MEDIATYPE_NONE)
+    }
+
+    pub fn get_media_state_flags(&self) -> MediaStateFlags {
+        self.0
+            .get_media_state_flags
+            .map(|f| MediaStateFlags::from_bits_truncate(unsafe { f(self.0.get_raw()) }.0))
+            .unwrap_or_default()
+    }
+
+    pub fn get_selection_text(&self) -> Result<CefString, CefStringError> {
+        let get_selection_text = self.0.get_selection_text.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_selection_text(self.0.get_raw())) }
+    }
+
+    /// Returns the misspelled word the menu was opened on, if any.
+    pub fn get_misspelled_word(&self) -> Result<CefString, CefStringError> {
+        let get_misspelled_word = self.0.get_misspelled_word.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_misspelled_word(self.0.get_raw())) }
+    }
+
+    /// Returns the spelling suggestions for the misspelled word.
+    pub fn get_dictionary_suggestions(&self) -> Vec<String> {
+        let Some(get_dictionary_suggestions) = self.0.get_dictionary_suggestions else {
+            return Vec::new();
+        };
+        let mut suggestions = CefStringList::default();
+
+        unsafe {
+            get_dictionary_suggestions(self.0.get_raw(), suggestions.get_raw_mut());
+            parse_string_list(suggestions.get_raw_mut())
+        }
+    }
+
+    pub fn is_editable(&self) -> bool {
+        self.0
+            .is_editable
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_spell_check_enabled(&self) -> bool {
+        self.0
+            .is_spell_check_enabled
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_edit_state_flags(&self) -> EditStateFlags {
+        self.0
+            .get_edit_state_flags
+            .map(|f| EditStateFlags::from_bits_truncate(unsafe { f(self.0.get_raw()) }.0))
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the menu was requested by a pepper plugin. The default items are not
+    /// present in that case.
+    pub fn is_custom_menu(&self) -> bool {
+        self.0
+            .is_custom_menu
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_run_context_menu_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct RunContextMenuCallback(cef_run_context_menu_callback_t);
+    pub fn cancel(&self);
+);
+
+impl RunContextMenuCallback {
+    /// Execute the command `command_id` of the menu, with the modifier keys and mouse buttons
+    /// held down when it was selected.
+    pub fn cont(&self, command_id: i32, event_flags: EventFlags) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), command_id, event_flags.into_raw()) };
+        }
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_run_quick_menu_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct RunQuickMenuCallback(cef_run_quick_menu_callback_t);
+    pub fn cancel(&self);
+);
+
+impl RunQuickMenuCallback {
+    /// Execute the command `command_id` of the quick menu.
+    pub fn cont(&self, command_id: i32, event_flags: EventFlags) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), command_id, event_flags.into_raw()) };
+        }
+    }
+}
+
+/// See [cef_context_menu_handler_t] for more documentation.
+///
+/// The methods are called on the browser process UI thread.
+pub trait ContextMenuHandler: Sized {
+    /// Called before the context menu is displayed. `model` contains the default items and can
+    /// be modified, or cleared to show no menu at all. Don't keep `params` or `model` beyond
+    /// this call.
+    fn on_before_context_menu(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _params: ContextMenuParams,
+        _model: MenuModel,
+    ) {
+    }
+
+    /// Called to display the context menu. Return `false` to show the default menu, or `true`
+    /// and call [RunContextMenuCallback::cont] with the selected command or
+    /// [RunContextMenuCallback::cancel], synchronously or asynchronously. Windowless browsers
+    /// must draw the menu themselves, see [MenuModel::items].
+    fn run_context_menu(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _params: ContextMenuParams,
+        _model: MenuModel,
+        _callback: RunContextMenuCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called to execute a command selected from the context menu. Return `true` if the command
+    /// was handled, or `false` for the default behavior. Default commands are listed in
+    /// [MenuId](crate::MenuId).
+    fn on_context_menu_command(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _params: ContextMenuParams,
+        _command_id: i32,
+        _event_flags: EventFlags,
+    ) -> bool {
+        false
+    }
+
+    /// Called when the context menu is dismissed, whether a command was selected or not.
+    fn on_context_menu_dismissed(&self, _browser: Browser, _frame: Frame) {}
+
+    /// Called to display the quick menu for a touch selection. `location` is the top left corner
+    /// and `size` the size of the selection, in view coordinates. Return `false` to show the
+    /// default menu, or `true` and call [RunQuickMenuCallback::cont] or
+    /// [RunQuickMenuCallback::cancel].
+    fn run_quick_menu(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _location: CefPoint,
+        _size: CefSize,
+        _edit_state_flags: QuickMenuEditStateFlags,
+        _callback: RunQuickMenuCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called to execute a command selected from the quick menu. Return `true` if the command
+    /// was handled, or `false` for the default behavior.
+    fn on_quick_menu_command(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _command_id: i32,
+        _event_flags: EventFlags,
+    ) -> bool {
+        false
+    }
+
+    /// Called when the quick menu is dismissed, whether a command was selected or not.
+    fn on_quick_menu_dismissed(&self, _browser: Browser, _frame: Frame) {}
+
+    fn get_raw(&self) -> *mut cef_context_menu_handler_t {
+        let mut object: cef_context_menu_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_before_context_menu = Some(on_before_context_menu::<Self>);
+        object.run_context_menu = Some(run_context_menu::<Self>);
+        object.on_context_menu_command = Some(on_context_menu_command::<Self>);
+        object.on_context_menu_dismissed = Some(on_context_menu_dismissed::<Self>);
+        object.run_quick_menu = Some(run_quick_menu::<Self>);
+        object.on_quick_menu_command = Some(on_quick_menu_command::<Self>);
+        object.on_quick_menu_dismissed = Some(on_quick_menu_dismissed::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl ContextMenuHandler for () {}
+
+extern "C" fn on_before_context_menu<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    params: *mut cef_context_menu_params_t,
+    model: *mut cef_menu_model_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let params = unsafe { ContextMenuParams::from_raw(params) };
+    let model = unsafe { MenuModel::from_raw(model) };
+
+    handler
+        .interface
+        .on_before_context_menu(browser, frame, params, model);
+}
+
+extern "C" fn run_context_menu<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    params: *mut cef_context_menu_params_t,
+    model: *mut cef_menu_model_t,
+    callback: *mut cef_run_context_menu_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let params = unsafe { ContextMenuParams::from_raw(params) };
+    let model = unsafe { MenuModel::from_raw(model) };
+    let callback = unsafe { RunContextMenuCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .run_context_menu(browser, frame, params, model, callback)
+        .into()
+}
+
+extern "C" fn on_context_menu_command<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    params: *mut cef_context_menu_params_t,
+    command_id: ::std::os::raw::c_int,
+    event_flags: cef_event_flags_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let params = unsafe { ContextMenuParams::from_raw(params) };
+    let event_flags = EventFlags::from_bits_truncate(event_flags.0);
+
+    handler
+        .interface
+        .on_context_menu_command(browser, frame, params, command_id, event_flags)
+        .into()
+}
+
+extern "C" fn on_context_menu_dismissed<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+
+    handler.interface.on_context_menu_dismissed(browser, frame);
+}
+
+extern "C" fn run_quick_menu<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    location: *const cef_point_t,
+    size: *const cef_size_t,
+    edit_state_flags: cef_quick_menu_edit_state_flags_t,
+    callback: *mut cef_run_quick_menu_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let location = unsafe { location.as_ref() }
+        .copied()
+        .map(CefPoint::from)
+        .unwrap_or_default();
+    let size = unsafe { size.as_ref() }
+        .copied()
+        .map(CefSize::from)
+        .unwrap_or_default();
+    let edit_state_flags = QuickMenuEditStateFlags::from_bits_truncate(edit_state_flags.0);
+    let callback = unsafe { RunQuickMenuCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .run_quick_menu(browser, frame, location, size, edit_state_flags, callback)
+        .into()
+}
+
+extern "C" fn on_quick_menu_command<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    command_id: ::std::os::raw::c_int,
+    event_flags: cef_event_flags_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let event_flags = EventFlags::from_bits_truncate(event_flags.0);
+
+    handler
+        .interface
+        .on_quick_menu_command(browser, frame, command_id, event_flags)
+        .into()
+}
+
+extern "C" fn on_quick_menu_dismissed<H: ContextMenuHandler>(
+    this: *mut cef_context_menu_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+
+    handler.interface.on_quick_menu_dismissed(browser, frame);
+}
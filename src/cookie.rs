@@ -0,0 +1,467 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    ptr::null_mut,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::SystemTime,
+};
+
+use cef_sys::{
+    cef_completion_callback_t, cef_cookie_manager_get_global_manager, cef_cookie_manager_t,
+    cef_cookie_same_site_t, cef_cookie_t, cef_cookie_visitor_t, cef_delete_cookies_callback_t,
+    cef_set_cookie_callback_t,
+};
+
+use crate::{
+    rc::RcImpl,
+    string::CefString,
+    utils::{basetime_to_system_time, system_time_to_basetime},
+    wrapper, CookiePriority,
+};
+
+/// The `SameSite` attribute of a [Cookie].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SameSite {
+    #[default]
+    Unspecified,
+    None,
+    Lax,
+    Strict,
+}
+
+impl From<cef_cookie_same_site_t> for SameSite {
+    fn from(value: cef_cookie_same_site_t) -> Self {
+        match value {
+            cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_NO_RESTRICTION => SameSite::None,
+            cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_LAX_MODE => SameSite::Lax,
+            cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_STRICT_MODE => SameSite::Strict,
+            _ => SameSite::Unspecified,
+        }
+    }
+}
+
+impl From<SameSite> for cef_cookie_same_site_t {
+    fn from(value: SameSite) -> Self {
+        match value {
+            SameSite::Unspecified => cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_UNSPECIFIED,
+            SameSite::None => cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_NO_RESTRICTION,
+            SameSite::Lax => cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_LAX_MODE,
+            SameSite::Strict => cef_cookie_same_site_t::CEF_COOKIE_SAME_SITE_STRICT_MODE,
+        }
+    }
+}
+
+/// See [cef_cookie_t] for more documentation.
+#[derive(Debug, Clone)]
+pub struct Cookie {
+    pub name: CefString,
+    pub value: CefString,
+    /// If empty a host cookie will be created instead of a domain cookie. Domain cookies are
+    /// stored with a leading "." and are visible to sub-domains whereas host cookies are not.
+    pub domain: CefString,
+    /// If non-empty only URLs at or below the path will get the cookie value.
+    pub path: CefString,
+    /// Only send the cookie for HTTPS requests.
+    pub secure: bool,
+    /// Only send the cookie for HTTP requests.
+    pub httponly: bool,
+    /// Ignored when setting a cookie.
+    pub creation: Option<SystemTime>,
+    /// Ignored when setting a cookie.
+    pub last_access: Option<SystemTime>,
+    /// `None` for session cookies.
+    pub expires: Option<SystemTime>,
+    pub same_site: SameSite,
+    pub priority: CookiePriority,
+}
+
+impl Default for Cookie {
+    fn default() -> Self {
+        Self {
+            name: CefString::default(),
+            value: CefString::default(),
+            domain: CefString::default(),
+            path: CefString::default(),
+            secure: false,
+            httponly: false,
+            creation: None,
+            last_access: None,
+            expires: None,
+            same_site: SameSite::default(),
+            priority: CookiePriority::CEF_COOKIE_PRIORITY_MEDIUM,
+        }
+    }
+}
+
+impl Cookie {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: CefString::new(name),
+            value: CefString::new(value),
+            ..Default::default()
+        }
+    }
+
+    /// Get the raw [cef_cookie_t] which borrows the strings of this cookie.
+    pub fn get_raw(&self) -> cef_cookie_t {
+        let null_time = cef_sys::cef_basetime_t { val: 0 };
+
+        cef_cookie_t {
+            name: self.name.get_raw(),
+            value: self.value.get_raw(),
+            domain: self.domain.get_raw(),
+            path: self.path.get_raw(),
+            secure: self.secure.into(),
+            httponly: self.httponly.into(),
+            creation: self
+                .creation
+                .map(system_time_to_basetime)
+                .unwrap_or(null_time),
+            last_access: self
+                .last_access
+                .map(system_time_to_basetime)
+                .unwrap_or(null_time),
+            has_expires: self.expires.is_some().into(),
+            expires: self
+                .expires
+                .map(system_time_to_basetime)
+                .unwrap_or(null_time),
+            same_site: self.same_site.into(),
+            priority: self.priority,
+        }
+    }
+}
+
+impl From<&cef_cookie_t> for Cookie {
+    fn from(value: &cef_cookie_t) -> Self {
+        Self {
+            name: CefString::try_from(value.name).unwrap_or_default(),
+            value: CefString::try_from(value.value).unwrap_or_default(),
+            domain: CefString::try_from(value.domain).unwrap_or_default(),
+            path: CefString::try_from(value.path).unwrap_or_default(),
+            secure: value.secure > 0,
+            httponly: value.httponly > 0,
+            creation: basetime_to_system_time(value.creation),
+            last_access: basetime_to_system_time(value.last_access),
+            expires: (value.has_expires > 0)
+                .then(|| basetime_to_system_time(value.expires))
+                .flatten(),
+            same_site: value.same_site.into(),
+            priority: value.priority,
+        }
+    }
+}
+
+/// A future resolving to the result of an asynchronous [CookieManager] operation. The callbacks
+/// are executed on the UI thread.
+#[derive(Debug)]
+pub struct CookieFuture<T>(Arc<Mutex<Slot<T>>>);
+
+#[derive(Debug)]
+struct Slot<T> {
+    value: Option<T>,
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> CookieFuture<T> {
+    fn new() -> (Self, Sender<T>) {
+        let slot = Arc::new(Mutex::new(Slot {
+            value: None,
+            done: false,
+            waker: None,
+        }));
+        (Self(slot.clone()), Sender(slot))
+    }
+}
+
+impl<T: Default> Future for CookieFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut slot = self.0.lock().unwrap();
+        if slot.done {
+            Poll::Ready(slot.value.take().unwrap_or_default())
+        } else {
+            slot.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Completes the [CookieFuture] when a value is sent or, with the default value, when dropped.
+/// Cef releases callbacks without executing them when an operation can't be performed.
+#[derive(Debug)]
+struct Sender<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> Sender<T> {
+    fn send(&self, value: T) {
+        self.0.lock().unwrap().value = Some(value);
+        self.complete();
+    }
+
+    fn complete(&self) {
+        let mut slot = self.0.lock().unwrap();
+        slot.done = true;
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_cookie_manager_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct CookieManager(cef_cookie_manager_t);
+);
+
+impl CookieManager {
+    /// Returns the global cookie manager. Cookies are stored in [Settings::cache_path] if
+    /// specified or in memory otherwise.
+    ///
+    /// [Settings::cache_path]: crate::Settings::cache_path
+    pub fn global() -> Option<CookieManager> {
+        let manager = unsafe { cef_cookie_manager_get_global_manager(null_mut()) };
+
+        (!manager.is_null()).then(|| unsafe { CookieManager::from_raw(manager) })
+    }
+
+    /// Visit all cookies with `visitor`. Returns `false` if cookies cannot be accessed.
+    pub fn visit_all_cookies_with<V: CookieVisitor>(&self, visitor: V) -> bool {
+        self.0
+            .visit_all_cookies
+            .map(|f| unsafe { f(self.0.get_raw(), visitor.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Visit the cookies sent to `url` with `visitor`. HTTP-only cookies are skipped unless
+    /// `include_http_only` is `true`. Returns `false` if cookies cannot be accessed.
+    pub fn visit_url_cookies_with<V: CookieVisitor>(
+        &self,
+        url: CefString,
+        include_http_only: bool,
+        visitor: V,
+    ) -> bool {
+        self.0
+            .visit_url_cookies
+            .map(|f| unsafe {
+                f(
+                    self.0.get_raw(),
+                    &url.get_raw(),
+                    include_http_only.into(),
+                    visitor.into_raw(),
+                )
+            } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns all cookies, ordered by longest path then earliest creation date. Resolves to an
+    /// empty list if cookies cannot be accessed.
+    pub fn visit_all_cookies(&self) -> CookieFuture<Vec<Cookie>> {
+        let (future, sender) = CookieFuture::new();
+        self.visit_all_cookies_with(CookieCollector::new(sender));
+        future
+    }
+
+    /// Returns the cookies sent to `url`, ordered by longest path then earliest creation date.
+    /// HTTP-only cookies are skipped unless `include_http_only` is `true`.
+    pub fn visit_url_cookies(
+        &self,
+        url: CefString,
+        include_http_only: bool,
+    ) -> CookieFuture<Vec<Cookie>> {
+        let (future, sender) = CookieFuture::new();
+        self.visit_url_cookies_with(url, include_http_only, CookieCollector::new(sender));
+        future
+    }
+
+    /// Set a cookie for `url`. Resolves to `true` if the cookie was set, or `false` if `url` or
+    /// the cookie is invalid.
+    pub fn set_cookie(&self, url: CefString, cookie: &Cookie) -> CookieFuture<bool> {
+        let (future, sender) = CookieFuture::new();
+        if let Some(f) = self.0.set_cookie {
+            let callback = SetCookieCallback(sender).into_raw();
+            unsafe {
+                f(
+                    self.0.get_raw(),
+                    &url.get_raw(),
+                    &cookie.get_raw(),
+                    callback,
+                )
+            };
+        }
+        future
+    }
+
+    /// Delete the cookies matching `url` and `cookie_name`. If both are `None` all cookies are
+    /// deleted, if only `url` is set all host cookies of that URL are deleted. Resolves to the
+    /// amount of cookies deleted.
+    pub fn delete_cookies(
+        &self,
+        url: Option<CefString>,
+        cookie_name: Option<CefString>,
+    ) -> CookieFuture<u32> {
+        let (future, sender) = CookieFuture::new();
+        if let Some(f) = self.0.delete_cookies {
+            let url = url.unwrap_or_default();
+            let cookie_name = cookie_name.unwrap_or_default();
+            let callback = DeleteCookiesCallback(sender).into_raw();
+            unsafe {
+                f(
+                    self.0.get_raw(),
+                    &url.get_raw(),
+                    &cookie_name.get_raw(),
+                    callback,
+                )
+            };
+        }
+        future
+    }
+
+    /// Flush the backing store to disk. Resolves once the flush is complete.
+    pub fn flush_store(&self) -> CookieFuture<()> {
+        let (future, sender) = CookieFuture::new();
+        if let Some(f) = self.0.flush_store {
+            let callback = CompletionCallback(sender).into_raw();
+            unsafe { f(self.0.get_raw(), callback) };
+        }
+        future
+    }
+}
+
+/// See [cef_cookie_visitor_t] for more documentation.
+///
+/// The methods are called on the UI thread. Visiting is complete once the visitor is dropped.
+pub trait CookieVisitor: Sized {
+    /// Called once for each cookie. `count` is the 0-based index of the cookie and `total` the
+    /// total amount of cookies. Set `delete_cookie` to `true` to delete the cookie currently
+    /// being visited. Return `false` to stop visiting cookies.
+    fn visit(
+        &mut self,
+        cookie: Cookie,
+        count: usize,
+        total: usize,
+        delete_cookie: &mut bool,
+    ) -> bool;
+
+    fn into_raw(self) -> *mut cef_cookie_visitor_t {
+        let mut object: cef_cookie_visitor_t = unsafe { std::mem::zeroed() };
+
+        object.visit = Some(visit::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+extern "C" fn visit<V: CookieVisitor>(
+    this: *mut cef_cookie_visitor_t,
+    cookie: *const cef_cookie_t,
+    count: ::std::os::raw::c_int,
+    total: ::std::os::raw::c_int,
+    delete_cookie: *mut ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let visitor: &mut RcImpl<_, V> = RcImpl::get(this);
+    let cookie = Cookie::from(unsafe { &*cookie });
+    let mut delete = false;
+
+    let res = visitor.interface.visit(
+        cookie,
+        count.max(0) as usize,
+        total.max(0) as usize,
+        &mut delete,
+    );
+    unsafe { *delete_cookie = delete.into() };
+
+    res.into()
+}
+
+struct CookieCollector {
+    cookies: Vec<Cookie>,
+    sender: Sender<Vec<Cookie>>,
+}
+
+impl CookieCollector {
+    fn new(sender: Sender<Vec<Cookie>>) -> Self {
+        Self {
+            cookies: Vec::new(),
+            sender,
+        }
+    }
+}
+
+impl CookieVisitor for CookieCollector {
+    fn visit(&mut self, cookie: Cookie, _count: usize, _total: usize, _delete: &mut bool) -> bool {
+        self.cookies.push(cookie);
+        true
+    }
+}
+
+impl Drop for CookieCollector {
+    fn drop(&mut self) {
+        self.sender.send(std::mem::take(&mut self.cookies));
+    }
+}
+
+struct SetCookieCallback(Sender<bool>);
+
+impl SetCookieCallback {
+    fn into_raw(self) -> *mut cef_set_cookie_callback_t {
+        let mut object: cef_set_cookie_callback_t = unsafe { std::mem::zeroed() };
+
+        object.on_complete = Some(on_set_cookie_complete);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+extern "C" fn on_set_cookie_complete(
+    this: *mut cef_set_cookie_callback_t,
+    success: ::std::os::raw::c_int,
+) {
+    let callback: &mut RcImpl<_, SetCookieCallback> = RcImpl::get(this);
+    callback.interface.0.send(success > 0);
+}
+
+struct DeleteCookiesCallback(Sender<u32>);
+
+impl DeleteCookiesCallback {
+    fn into_raw(self) -> *mut cef_delete_cookies_callback_t {
+        let mut object: cef_delete_cookies_callback_t = unsafe { std::mem::zeroed() };
+
+        object.on_complete = Some(on_delete_cookies_complete);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+extern "C" fn on_delete_cookies_complete(
+    this: *mut cef_delete_cookies_callback_t,
+    num_deleted: ::std::os::raw::c_int,
+) {
+    let callback: &mut RcImpl<_, DeleteCookiesCallback> = RcImpl::get(this);
+    callback.interface.0.send(num_deleted.max(0) as u32);
+}
+
+struct CompletionCallback(Sender<()>);
+
+impl CompletionCallback {
+    fn into_raw(self) -> *mut cef_completion_callback_t {
+        let mut object: cef_completion_callback_t = unsafe { std::mem::zeroed() };
+
+        object.on_complete = Some(on_complete);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+extern "C" fn on_complete(this: *mut cef_completion_callback_t) {
+    let callback: &mut RcImpl<_, CompletionCallback> = RcImpl::get(this);
+    callback.interface.0.send(());
+}
@@ -0,0 +1,90 @@
+use cef_sys::{
+    cef_browser_t, cef_cookie_access_filter_t, cef_cookie_t, cef_frame_t, cef_request_t,
+    cef_response_t,
+};
+
+use crate::{
+    cookie::Cookie, frame::Frame, rc::RcImpl, request::Request,
+    resource_request_handler::browser_and_frame, response::Response, Browser,
+};
+
+/// See [cef_cookie_access_filter_t] for more documentation.
+///
+/// The methods are called on the IO thread. `browser` and `frame` are `None` for requests
+/// originating from service workers or URL requests.
+pub trait CookieAccessFilter: Sized {
+    /// Called before a cookie is sent with `request`. Return `true` to allow the cookie to be
+    /// sent or `false` to block it. `request` may not be modified in this method.
+    fn can_send_cookie(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _cookie: Cookie,
+    ) -> bool {
+        true
+    }
+
+    /// Called before a cookie set by `response` is saved. Return `true` to allow the cookie to
+    /// be saved or `false` to block it. `request` and `response` may not be modified in this
+    /// method.
+    fn can_save_cookie(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _response: Response,
+        _cookie: Cookie,
+    ) -> bool {
+        true
+    }
+
+    fn get_raw(&self) -> *mut cef_cookie_access_filter_t {
+        let mut object: cef_cookie_access_filter_t = unsafe { std::mem::zeroed() };
+
+        object.can_send_cookie = Some(can_send_cookie::<Self>);
+        object.can_save_cookie = Some(can_save_cookie::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl CookieAccessFilter for () {}
+
+extern "C" fn can_send_cookie<F: CookieAccessFilter>(
+    this: *mut cef_cookie_access_filter_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    cookie: *const cef_cookie_t,
+) -> ::std::os::raw::c_int {
+    let filter: &mut RcImpl<_, &F> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let cookie = Cookie::from(unsafe { &*cookie });
+
+    filter
+        .interface
+        .can_send_cookie(browser, frame, request, cookie)
+        .into()
+}
+
+extern "C" fn can_save_cookie<F: CookieAccessFilter>(
+    this: *mut cef_cookie_access_filter_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    response: *mut cef_response_t,
+    cookie: *const cef_cookie_t,
+) -> ::std::os::raw::c_int {
+    let filter: &mut RcImpl<_, &F> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let response = unsafe { Response::from_raw(response) };
+    let cookie = Cookie::from(unsafe { &*cookie });
+
+    filter
+        .interface
+        .can_save_cookie(browser, frame, request, response, cookie)
+        .into()
+}
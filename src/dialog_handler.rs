@@ -0,0 +1,191 @@
+use std::path::PathBuf;
+
+use cef_sys::{
+    cef_browser_t, cef_dialog_handler_t, cef_file_dialog_callback_t, cef_file_dialog_mode_t,
+    cef_run_file_dialog_callback_t, cef_string_list_t, cef_string_t,
+};
+
+use crate::{
+    rc::RcImpl,
+    string::{parse_string_list, CefString, CefStringList},
+    wrapper, Browser, FileDialogMode,
+};
+
+wrapper!(
+    #[doc = "See [cef_file_dialog_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct FileDialogCallback(cef_file_dialog_callback_t);
+    pub fn cancel(&self);
+);
+
+impl FileDialogCallback {
+    /// Select `file_paths`. It must contain a single path unless the mode is
+    /// [FileDialogMode::FILE_DIALOG_OPEN_MULTIPLE]. An empty list cancels the dialog.
+    pub fn cont(&self, file_paths: Vec<PathBuf>) {
+        if let Some(f) = self.0.cont {
+            let mut list: CefStringList = file_paths
+                .iter()
+                .map(|path| CefString::new(&path.to_string_lossy()))
+                .collect();
+            unsafe { f(self.0.get_raw(), list.get_raw_mut()) };
+        }
+    }
+}
+
+/// See [cef_dialog_handler_t] for more documentation.
+///
+/// The methods are called on the browser process UI thread.
+pub trait DialogHandler: Sized {
+    /// Called to run a file chooser dialog, like for `<input type="file">`. `title` is `None`
+    /// for the default title. `default_file_path` is the file name or path selected initially,
+    /// if any. `accept_filters` restricts the selectable files, each filter is a lower case MIME
+    /// type like `image/*`, an extension like `.png` or a description followed by extensions
+    /// like `Images|.png;.jpg`. See [accept_extensions] to get the extensions.
+    ///
+    /// Return `true` and call [FileDialogCallback::cont] or [FileDialogCallback::cancel] to
+    /// answer the dialog, synchronously or from any thread. Return `false` for the default
+    /// dialog.
+    fn on_file_dialog(
+        &self,
+        _browser: Browser,
+        _mode: FileDialogMode,
+        _title: Option<CefString>,
+        _default_file_path: Option<PathBuf>,
+        _accept_filters: Vec<String>,
+        _callback: FileDialogCallback,
+    ) -> bool {
+        false
+    }
+
+    fn get_raw(&self) -> *mut cef_dialog_handler_t {
+        let mut object: cef_dialog_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_file_dialog = Some(on_file_dialog::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl DialogHandler for () {}
+
+/// A [DialogHandler] answering every file dialog immediately with the same paths, for headless
+/// automation. Only the first path is selected unless the mode is
+/// [FileDialogMode::FILE_DIALOG_OPEN_MULTIPLE]. An empty list cancels every dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoSelect(pub Vec<PathBuf>);
+
+impl DialogHandler for AutoSelect {
+    fn on_file_dialog(
+        &self,
+        _browser: Browser,
+        mode: FileDialogMode,
+        _title: Option<CefString>,
+        _default_file_path: Option<PathBuf>,
+        _accept_filters: Vec<String>,
+        callback: FileDialogCallback,
+    ) -> bool {
+        let mut file_paths = self.0.clone();
+        if mode != FileDialogMode::FILE_DIALOG_OPEN_MULTIPLE {
+            file_paths.truncate(1);
+        }
+
+        callback.cont(file_paths);
+        true
+    }
+}
+
+/// See [cef_run_file_dialog_callback_t] for more documentation.
+///
+/// Used with [BrowserHost::run_file_dialog](crate::BrowserHost::run_file_dialog). Closures
+/// taking the selected paths implement it.
+pub trait RunFileDialogCallback: Sized {
+    /// Called after the dialog is dismissed with the selected paths. `file_paths` is empty if
+    /// the selection was canceled.
+    fn on_file_dialog_dismissed(&mut self, file_paths: Vec<PathBuf>);
+
+    fn into_raw(self) -> *mut cef_run_file_dialog_callback_t {
+        let mut object: cef_run_file_dialog_callback_t = unsafe { std::mem::zeroed() };
+
+        object.on_file_dialog_dismissed = Some(on_file_dialog_dismissed::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl<F: FnMut(Vec<PathBuf>)> RunFileDialogCallback for F {
+    fn on_file_dialog_dismissed(&mut self, file_paths: Vec<PathBuf>) {
+        self(file_paths)
+    }
+}
+
+/// Returns the file extensions listed in `accept_filters`, without the leading dot. MIME types
+/// are skipped.
+pub fn accept_extensions(accept_filters: &[String]) -> Vec<String> {
+    accept_filters
+        .iter()
+        .flat_map(|filter| {
+            let extensions = filter.rsplit_once('|').map_or(filter.as_str(), |(_, e)| e);
+            extensions.split(';')
+        })
+        .filter_map(|extension| extension.trim().strip_prefix('.'))
+        .map(|extension| extension.to_string())
+        .collect()
+}
+
+fn parse_paths(file_paths: cef_string_list_t) -> Vec<PathBuf> {
+    if file_paths.is_null() {
+        return Vec::new();
+    }
+
+    unsafe { parse_string_list(file_paths) }
+        .into_iter()
+        .map(PathBuf::from)
+        .collect()
+}
+
+extern "C" fn on_file_dialog<H: DialogHandler>(
+    this: *mut cef_dialog_handler_t,
+    browser: *mut cef_browser_t,
+    mode: cef_file_dialog_mode_t,
+    title: *const cef_string_t,
+    default_file_path: *const cef_string_t,
+    accept_filters: cef_string_list_t,
+    callback: *mut cef_file_dialog_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let title = unsafe { CefString::from_raw(title).ok() }.filter(|t| !t.to_string().is_empty());
+    let default_file_path = unsafe { CefString::from_raw(default_file_path).ok() }
+        .map(|p| p.to_string())
+        .filter(|p| !p.is_empty())
+        .map(PathBuf::from);
+    let accept_filters = if accept_filters.is_null() {
+        Vec::new()
+    } else {
+        unsafe { parse_string_list(accept_filters) }
+    };
+    let callback = unsafe { FileDialogCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_file_dialog(
+            browser,
+            mode,
+            title,
+            default_file_path,
+            accept_filters,
+            callback,
+        )
+        .into()
+}
+
+extern "C" fn on_file_dialog_dismissed<C: RunFileDialogCallback>(
+    this: *mut cef_run_file_dialog_callback_t,
+    file_paths: cef_string_list_t,
+) {
+    let callback: &mut RcImpl<_, C> = RcImpl::get(this);
+
+    callback
+        .interface
+        .on_file_dialog_dismissed(parse_paths(file_paths));
+}
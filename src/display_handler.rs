@@ -0,0 +1,301 @@
+use std::os::raw::c_ulong;
+
+use cef_sys::{
+    cef_browser_t, cef_cursor_info_t, cef_cursor_type_t, cef_display_handler_t, cef_frame_t,
+    cef_log_severity_t, cef_size_t, cef_string_list_t, cef_string_t,
+};
+
+use crate::{
+    frame::Frame,
+    rc::RcImpl,
+    render_utils::{CefPoint, CefSize},
+    string::{parse_string_list, CefString},
+    Browser, CursorType, LogSeverity,
+};
+
+/// The image of a custom cursor. See [cef_cursor_info_t] for more documentation.
+#[derive(Debug, Clone, Default)]
+pub struct CursorInfo {
+    pub hotspot: CefPoint,
+    pub image_scale_factor: f32,
+    /// The size of the image in pixels.
+    pub size: CefSize,
+    /// A copy of the image in BGRA format, `size.width * size.height * 4` bytes.
+    pub buffer: Vec<u8>,
+}
+
+impl From<&cef_cursor_info_t> for CursorInfo {
+    fn from(raw: &cef_cursor_info_t) -> Self {
+        let size = CefSize::from(raw.size);
+        let len = size.width as usize * size.height as usize * 4;
+        let buffer = if raw.buffer.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(raw.buffer as *const u8, len) }.to_vec()
+        };
+
+        Self {
+            hotspot: raw.hotspot.into(),
+            image_scale_factor: raw.image_scale_factor,
+            size,
+            buffer,
+        }
+    }
+}
+
+/// See [cef_display_handler_t] for more documentation.
+///
+/// The methods are called on the UI thread.
+pub trait DisplayHandler: Sized {
+    /// Called when the URL of a frame has changed.
+    fn on_address_change(&self, _browser: Browser, _frame: Frame, _url: CefString) {}
+
+    /// Called when the page title has changed.
+    fn on_title_change(&self, _browser: Browser, _title: CefString) {}
+
+    /// Called when the page icon has changed.
+    fn on_favicon_urlchange(&self, _browser: Browser, _icon_urls: Vec<String>) {}
+
+    /// Called when web content in the page toggles fullscreen mode. The client is responsible
+    /// for resizing the browser if needed.
+    fn on_fullscreen_mode_change(&self, _browser: Browser, _fullscreen: bool) {}
+
+    /// Called when the browser is about to display a tooltip. `text` can be modified to change
+    /// the tooltip. Return `true` to handle the display of the tooltip yourself.
+    fn on_tooltip(&self, _browser: Browser, _text: &mut CefString) -> bool {
+        false
+    }
+
+    /// Called when the browser receives a status message.
+    fn on_status_message(&self, _browser: Browser, _value: CefString) {}
+
+    /// Called to display a console message. Return `true` to stop the message from being output
+    /// to the console.
+    fn on_console_message(
+        &self,
+        _browser: Browser,
+        _level: LogSeverity,
+        _message: CefString,
+        _source: CefString,
+        _line: i32,
+    ) -> bool {
+        false
+    }
+
+    /// Called when auto-resize is enabled and the contents have auto-resized. Return `true` if
+    /// the resize was handled.
+    fn on_auto_resize(&self, _browser: Browser, _new_size: CefSize) -> bool {
+        false
+    }
+
+    /// Called when the overall page loading progress has changed. `progress` ranges from 0.0 to
+    /// 1.0.
+    fn on_loading_progress_change(&self, _browser: Browser, _progress: f64) {}
+
+    /// Called when the cursor changes. `custom_cursor_info` is set if `type_` is
+    /// [CursorType::CT_CUSTOM]. Return `true` if the cursor change was handled or `false` for
+    /// the default handling.
+    fn on_cursor_change(
+        &self,
+        _browser: Browser,
+        _cursor: c_ulong,
+        _type_: CursorType,
+        _custom_cursor_info: Option<CursorInfo>,
+    ) -> bool {
+        false
+    }
+
+    /// Called when the browser starts or stops accessing the camera or microphone.
+    fn on_media_access_change(
+        &self,
+        _browser: Browser,
+        _has_video_access: bool,
+        _has_audio_access: bool,
+    ) {
+    }
+
+    fn get_raw(&self) -> *mut cef_display_handler_t {
+        let mut object: cef_display_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_address_change = Some(on_address_change::<Self>);
+        object.on_title_change = Some(on_title_change::<Self>);
+        object.on_favicon_urlchange = Some(on_favicon_urlchange::<Self>);
+        object.on_fullscreen_mode_change = Some(on_fullscreen_mode_change::<Self>);
+        object.on_tooltip = Some(on_tooltip::<Self>);
+        object.on_status_message = Some(on_status_message::<Self>);
+        object.on_console_message = Some(on_console_message::<Self>);
+        object.on_auto_resize = Some(on_auto_resize::<Self>);
+        object.on_loading_progress_change = Some(on_loading_progress_change::<Self>);
+        object.on_cursor_change = Some(on_cursor_change::<Self>);
+        object.on_media_access_change = Some(on_media_access_change::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl DisplayHandler for () {}
+
+extern "C" fn on_address_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    url: *const cef_string_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let url = unsafe { CefString::from_raw(url).unwrap_or_default() };
+
+    handler.interface.on_address_change(browser, frame, url);
+}
+
+extern "C" fn on_title_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    title: *const cef_string_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let title = unsafe { CefString::from_raw(title).unwrap_or_default() };
+
+    handler.interface.on_title_change(browser, title);
+}
+
+extern "C" fn on_favicon_urlchange<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    icon_urls: cef_string_list_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let icon_urls = if icon_urls.is_null() {
+        Vec::new()
+    } else {
+        unsafe { parse_string_list(icon_urls) }
+    };
+
+    handler.interface.on_favicon_urlchange(browser, icon_urls);
+}
+
+extern "C" fn on_fullscreen_mode_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    fullscreen: ::std::os::raw::c_int,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler
+        .interface
+        .on_fullscreen_mode_change(browser, fullscreen > 0);
+}
+
+extern "C" fn on_tooltip<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    text: *mut cef_string_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let mut tooltip = unsafe { CefString::from_raw(text).unwrap_or_default() };
+    let old_tooltip = tooltip.clone();
+
+    let res = handler.interface.on_tooltip(browser, &mut tooltip);
+
+    if tooltip.0 != old_tooltip.0 {
+        unsafe { tooltip.set_raw(text) };
+    }
+    res.into()
+}
+
+extern "C" fn on_status_message<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    value: *const cef_string_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let value = unsafe { CefString::from_raw(value).unwrap_or_default() };
+
+    handler.interface.on_status_message(browser, value);
+}
+
+extern "C" fn on_console_message<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    level: cef_log_severity_t,
+    message: *const cef_string_t,
+    source: *const cef_string_t,
+    line: ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let message = unsafe { CefString::from_raw(message).unwrap_or_default() };
+    let source = unsafe { CefString::from_raw(source).unwrap_or_default() };
+
+    handler
+        .interface
+        .on_console_message(browser, level, message, source, line)
+        .into()
+}
+
+extern "C" fn on_auto_resize<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    new_size: *const cef_size_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let new_size = if new_size.is_null() {
+        CefSize::default()
+    } else {
+        unsafe { *new_size }.into()
+    };
+
+    handler.interface.on_auto_resize(browser, new_size).into()
+}
+
+extern "C" fn on_loading_progress_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    progress: f64,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler
+        .interface
+        .on_loading_progress_change(browser, progress);
+}
+
+extern "C" fn on_cursor_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    cursor: c_ulong,
+    type_: cef_cursor_type_t,
+    custom_cursor_info: *const cef_cursor_info_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let custom_cursor_info = (type_ == CursorType::CT_CUSTOM && !custom_cursor_info.is_null())
+        .then(|| CursorInfo::from(unsafe { &*custom_cursor_info }));
+
+    handler
+        .interface
+        .on_cursor_change(browser, cursor, type_, custom_cursor_info)
+        .into()
+}
+
+extern "C" fn on_media_access_change<H: DisplayHandler>(
+    this: *mut cef_display_handler_t,
+    browser: *mut cef_browser_t,
+    has_video_access: ::std::os::raw::c_int,
+    has_audio_access: ::std::os::raw::c_int,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler
+        .interface
+        .on_media_access_change(browser, has_video_access > 0, has_audio_access > 0);
+}
@@ -0,0 +1,129 @@
+use cef_sys::{
+    cef_before_download_callback_t, cef_browser_t, cef_download_handler_t,
+    cef_download_item_callback_t, cef_download_item_t, cef_string_t,
+};
+
+use crate::{download_item::DownloadItem, rc::RcImpl, string::CefString, wrapper, Browser};
+
+wrapper!(
+    #[doc = "See [cef_before_download_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct BeforeDownloadCallback(cef_before_download_callback_t);
+);
+
+impl BeforeDownloadCallback {
+    /// Continue the download.
+    ///
+    /// Set `download_path` to the full file path for the download including the file name or
+    /// leave it empty to use the suggested name and the default temp directory. Set `show_dialog`
+    /// to `true` if you do wish to show the default "Save As" dialog.
+    pub fn cont(&self, download_path: CefString, show_dialog: bool) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), &download_path.get_raw(), show_dialog.into()) };
+        }
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_download_item_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct DownloadItemCallback(cef_download_item_callback_t);
+    pub fn cancel(&self);
+    pub fn pause(&self);
+    pub fn resume(&self);
+);
+
+/// See [cef_download_handler_t] for more documentation.
+pub trait DownloadHandler: Sized {
+    /// Called before a download begins in response to a user-initiated action. Return `true` to
+    /// proceed with the download or `false` to cancel it.
+    fn can_download(&self, _browser: Browser, _url: CefString, _request_method: CefString) -> bool {
+        true
+    }
+
+    /// Called before a download begins. Execute `callback` either asynchronously or in this
+    /// function to continue or cancel the download. By default the download will be canceled.
+    fn on_before_download(
+        &self,
+        _browser: Browser,
+        _download_item: DownloadItem,
+        _suggested_name: CefString,
+        _callback: BeforeDownloadCallback,
+    ) {
+    }
+
+    /// Called when a download's status or progress information has been updated. This may be
+    /// called multiple times before and after [DownloadHandler::on_before_download]. Execute
+    /// `callback` either asynchronously or in this function to cancel, pause or resume the
+    /// download.
+    fn on_download_updated(
+        &self,
+        _browser: Browser,
+        _download_item: DownloadItem,
+        _callback: DownloadItemCallback,
+    ) {
+    }
+
+    fn get_raw(&self) -> *mut cef_download_handler_t {
+        let mut object: cef_download_handler_t = unsafe { std::mem::zeroed() };
+
+        object.can_download = Some(can_download::<Self>);
+        object.on_before_download = Some(on_before_download::<Self>);
+        object.on_download_updated = Some(on_download_updated::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl DownloadHandler for () {}
+
+extern "C" fn can_download<D: DownloadHandler>(
+    this: *mut cef_download_handler_t,
+    browser: *mut cef_browser_t,
+    url: *const cef_string_t,
+    request_method: *const cef_string_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &D> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let url = unsafe { CefString::from_raw(url).unwrap_or_default() };
+    let request_method = unsafe { CefString::from_raw(request_method).unwrap_or_default() };
+
+    handler
+        .interface
+        .can_download(browser, url, request_method)
+        .into()
+}
+
+extern "C" fn on_before_download<D: DownloadHandler>(
+    this: *mut cef_download_handler_t,
+    browser: *mut cef_browser_t,
+    download_item: *mut cef_download_item_t,
+    suggested_name: *const cef_string_t,
+    callback: *mut cef_before_download_callback_t,
+) {
+    let handler: &mut RcImpl<_, &D> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let download_item = unsafe { DownloadItem::from_raw(download_item) };
+    let suggested_name = unsafe { CefString::from_raw(suggested_name).unwrap_or_default() };
+    let callback = unsafe { BeforeDownloadCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_before_download(browser, download_item, suggested_name, callback);
+}
+
+extern "C" fn on_download_updated<D: DownloadHandler>(
+    this: *mut cef_download_handler_t,
+    browser: *mut cef_browser_t,
+    download_item: *mut cef_download_item_t,
+    callback: *mut cef_download_item_callback_t,
+) {
+    let handler: &mut RcImpl<_, &D> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let download_item = unsafe { DownloadItem::from_raw(download_item) };
+    let callback = unsafe { DownloadItemCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_download_updated(browser, download_item, callback);
+}
@@ -0,0 +1,167 @@
+use std::time::SystemTime;
+
+use cef_sys::cef_download_item_t;
+
+use crate::{
+    string::{CefString, CefStringError},
+    utils::basetime_to_system_time,
+    wrapper, DownloadInterruptReason,
+};
+
+/// The state of a [DownloadItem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadState {
+    InProgress,
+    Complete,
+    Canceled,
+    Interrupted(DownloadInterruptReason),
+}
+
+wrapper!(
+    #[doc = "See [cef_download_item_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct DownloadItem(cef_download_item_t);
+);
+
+impl DownloadItem {
+    pub fn is_valid(&self) -> bool {
+        self.0
+            .is_valid
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.0
+            .is_in_progress
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.0
+            .is_complete
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_canceled(&self) -> bool {
+        self.0
+            .is_canceled
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_interrupted(&self) -> bool {
+        self.0
+            .is_interrupted
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_interrupt_reason(&self) -> DownloadInterruptReason {
+        self.0
+            .get_interrupt_reason
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(DownloadInterruptReason::CEF_DOWNLOAD_INTERRUPT_REASON_NONE)
+    }
+
+    /// Returns the current state of the download.
+    pub fn get_state(&self) -> DownloadState {
+        if self.is_complete() {
+            DownloadState::Complete
+        } else if self.is_canceled() {
+            DownloadState::Canceled
+        } else if self.is_interrupted() {
+            DownloadState::Interrupted(self.get_interrupt_reason())
+        } else {
+            DownloadState::InProgress
+        }
+    }
+
+    /// Returns a simple speed estimate in bytes/s.
+    pub fn get_current_speed(&self) -> i64 {
+        self.0
+            .get_current_speed
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    /// Returns the rough percent complete or `None` if the receive total size is unknown.
+    pub fn get_percent_complete(&self) -> Option<u32> {
+        self.0
+            .get_percent_complete
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .filter(|percent| *percent >= 0)
+            .map(|percent| percent as u32)
+    }
+
+    pub fn get_total_bytes(&self) -> i64 {
+        self.0
+            .get_total_bytes
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_received_bytes(&self) -> i64 {
+        self.0
+            .get_received_bytes
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_start_time(&self) -> Option<SystemTime> {
+        self.0
+            .get_start_time
+            .and_then(|f| basetime_to_system_time(unsafe { f(self.0.get_raw()) }))
+    }
+
+    pub fn get_end_time(&self) -> Option<SystemTime> {
+        self.0
+            .get_end_time
+            .and_then(|f| basetime_to_system_time(unsafe { f(self.0.get_raw()) }))
+    }
+
+    pub fn get_full_path(&self) -> Result<CefString, CefStringError> {
+        let get_full_path = self.0.get_full_path.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_full_path(self.0.get_raw())) }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.0
+            .get_id
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_url(&self) -> Result<CefString, CefStringError> {
+        let get_url = self.0.get_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_url(self.0.get_raw())) }
+    }
+
+    pub fn get_original_url(&self) -> Result<CefString, CefStringError> {
+        let get_original_url = self.0.get_original_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_original_url(self.0.get_raw())) }
+    }
+
+    pub fn get_suggested_file_name(&self) -> Result<CefString, CefStringError> {
+        let get_suggested_file_name = self.0.get_suggested_file_name.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_suggested_file_name(self.0.get_raw())) }
+    }
+
+    pub fn get_content_disposition(&self) -> Result<CefString, CefStringError> {
+        let get_content_disposition = self.0.get_content_disposition.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_content_disposition(self.0.get_raw())) }
+    }
+
+    pub fn get_mime_type(&self) -> Result<CefString, CefStringError> {
+        let get_mime_type = self.0.get_mime_type.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_mime_type(self.0.get_raw())) }
+    }
+}
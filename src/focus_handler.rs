@@ -0,0 +1,66 @@
+use cef_sys::{cef_browser_t, cef_focus_handler_t, cef_focus_source_t};
+
+use crate::{rc::RcImpl, Browser, FocusSource};
+
+/// See [cef_focus_handler_t] for more documentation.
+///
+/// The methods are called on the UI thread.
+pub trait FocusHandler: Sized {
+    /// Called when the browser component is about to lose focus, for instance when tabbing past
+    /// the last focusable element of the page. `next` is `true` when moving forward and `false`
+    /// when moving backward.
+    fn on_take_focus(&self, _browser: Browser, _next: bool) {}
+
+    /// Called when the browser component is requesting focus. Return `false` to allow the focus
+    /// to be set or `true` to cancel it.
+    fn on_set_focus(&self, _browser: Browser, _source: FocusSource) -> bool {
+        false
+    }
+
+    /// Called when the browser component has received focus.
+    fn on_got_focus(&self, _browser: Browser) {}
+
+    fn get_raw(&self) -> *mut cef_focus_handler_t {
+        let mut object: cef_focus_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_take_focus = Some(on_take_focus::<Self>);
+        object.on_set_focus = Some(on_set_focus::<Self>);
+        object.on_got_focus = Some(on_got_focus::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl FocusHandler for () {}
+
+extern "C" fn on_take_focus<H: FocusHandler>(
+    this: *mut cef_focus_handler_t,
+    browser: *mut cef_browser_t,
+    next: ::std::os::raw::c_int,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler.interface.on_take_focus(browser, next > 0);
+}
+
+extern "C" fn on_set_focus<H: FocusHandler>(
+    this: *mut cef_focus_handler_t,
+    browser: *mut cef_browser_t,
+    source: cef_focus_source_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler.interface.on_set_focus(browser, source).into()
+}
+
+extern "C" fn on_got_focus<H: FocusHandler>(
+    this: *mut cef_focus_handler_t,
+    browser: *mut cef_browser_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler.interface.on_got_focus(browser);
+}
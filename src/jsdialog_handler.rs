@@ -0,0 +1,230 @@
+use cef_sys::{
+    cef_browser_t, cef_jsdialog_callback_t, cef_jsdialog_handler_t, cef_jsdialog_type_t,
+    cef_string_t,
+};
+
+use crate::{rc::RcImpl, string::CefString, wrapper, Browser, JsDialogType};
+
+wrapper!(
+    #[doc = "See [cef_jsdialog_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct JsDialogCallback(cef_jsdialog_callback_t);
+);
+
+impl JsDialogCallback {
+    /// Close the dialog. `success` is `true` if the OK button was pressed, `user_input` is the
+    /// value entered for prompt dialogs.
+    pub fn cont(&self, success: bool, user_input: Option<CefString>) {
+        if let Some(f) = self.0.cont {
+            let user_input = user_input.unwrap_or_default();
+            unsafe { f(self.0.get_raw(), success.into(), &user_input.get_raw()) };
+        }
+    }
+
+    /// Press the OK button of the dialog.
+    pub fn accept(&self) {
+        self.cont(true, None);
+    }
+
+    /// Press the Cancel button of the dialog.
+    pub fn dismiss(&self) {
+        self.cont(false, None);
+    }
+}
+
+/// See [cef_jsdialog_handler_t] for more documentation.
+///
+/// The methods are called on the browser process UI thread. Without a handler, windowless
+/// browsers can't display dialogs and cancel them.
+pub trait JsDialogHandler: Sized {
+    /// Called to run a JavaScript `alert`, `confirm` or `prompt` dialog. `origin_url` is `None`
+    /// for dialogs opened by the page of a data URL. `default_prompt_text` is only used for
+    /// prompt dialogs.
+    ///
+    /// Return `true` to display a custom dialog and call [JsDialogCallback::cont] once it is
+    /// closed, synchronously or from any thread. Return `false` for the default dialog, or set
+    /// `suppress_message` to `true` and return `false` to cancel the dialog without displaying
+    /// it, which behaves like pressing Cancel.
+    #[allow(clippy::too_many_arguments)]
+    fn on_jsdialog(
+        &self,
+        _browser: Browser,
+        _origin_url: Option<CefString>,
+        _dialog_type: JsDialogType,
+        _message_text: CefString,
+        _default_prompt_text: CefString,
+        _callback: JsDialogCallback,
+        _suppress_message: &mut bool,
+    ) -> bool {
+        false
+    }
+
+    /// Called to ask the user if they want to leave the page, when the page has an
+    /// `onbeforeunload` handler. `message_text` is not provided by recent versions of Chromium.
+    ///
+    /// Return `true` and call [JsDialogCallback::cont] with `success` set to `true` to leave the
+    /// page or `false` to stay, or return `false` for the default dialog.
+    fn on_before_unload_dialog(
+        &self,
+        _browser: Browser,
+        _message_text: CefString,
+        _is_reload: bool,
+        _callback: JsDialogCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called to cancel pending dialogs and reset the saved state, for instance when the page
+    /// navigates away.
+    fn on_reset_dialog_state(&self, _browser: Browser) {}
+
+    /// Called when the default dialog is closed.
+    fn on_dialog_closed(&self, _browser: Browser) {}
+
+    fn get_raw(&self) -> *mut cef_jsdialog_handler_t {
+        let mut object: cef_jsdialog_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_jsdialog = Some(on_jsdialog::<Self>);
+        object.on_before_unload_dialog = Some(on_before_unload_dialog::<Self>);
+        object.on_reset_dialog_state = Some(on_reset_dialog_state::<Self>);
+        object.on_dialog_closed = Some(on_dialog_closed::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl JsDialogHandler for () {}
+
+/// A [JsDialogHandler] answering every dialog immediately without displaying it, for headless
+/// automation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoAnswer {
+    /// Whether to press OK on alert, confirm and prompt dialogs.
+    pub accept: bool,
+    /// The text entered in prompt dialogs. The default prompt text is used if `None`.
+    pub prompt_text: Option<String>,
+    /// Whether to leave the page on `onbeforeunload` dialogs.
+    pub leave_page: bool,
+}
+
+impl AutoAnswer {
+    /// Press OK on every dialog and leave pages.
+    pub fn accept_all() -> Self {
+        Self {
+            accept: true,
+            prompt_text: None,
+            leave_page: true,
+        }
+    }
+
+    /// Press Cancel on every dialog and stay on pages.
+    pub fn dismiss_all() -> Self {
+        Self::default()
+    }
+}
+
+impl JsDialogHandler for AutoAnswer {
+    fn on_jsdialog(
+        &self,
+        _browser: Browser,
+        _origin_url: Option<CefString>,
+        dialog_type: JsDialogType,
+        _message_text: CefString,
+        default_prompt_text: CefString,
+        callback: JsDialogCallback,
+        _suppress_message: &mut bool,
+    ) -> bool {
+        let user_input = match (dialog_type, &self.prompt_text) {
+            (JsDialogType::JSDIALOGTYPE_PROMPT, Some(text)) => Some(CefString::new(text)),
+            (JsDialogType::JSDIALOGTYPE_PROMPT, None) => Some(default_prompt_text),
+            _ => None,
+        };
+
+        callback.cont(self.accept, user_input.filter(|_| self.accept));
+        true
+    }
+
+    fn on_before_unload_dialog(
+        &self,
+        _browser: Browser,
+        _message_text: CefString,
+        _is_reload: bool,
+        callback: JsDialogCallback,
+    ) -> bool {
+        callback.cont(self.leave_page, None);
+        true
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "C" fn on_jsdialog<H: JsDialogHandler>(
+    this: *mut cef_jsdialog_handler_t,
+    browser: *mut cef_browser_t,
+    origin_url: *const cef_string_t,
+    dialog_type: cef_jsdialog_type_t,
+    message_text: *const cef_string_t,
+    default_prompt_text: *const cef_string_t,
+    callback: *mut cef_jsdialog_callback_t,
+    suppress_message: *mut ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let origin_url = unsafe { CefString::from_raw(origin_url).ok() };
+    let message_text = unsafe { CefString::from_raw(message_text).unwrap_or_default() };
+    let default_prompt_text =
+        unsafe { CefString::from_raw(default_prompt_text).unwrap_or_default() };
+    let callback = unsafe { JsDialogCallback::from_raw(callback) };
+    let mut suppress = false;
+
+    let res = handler.interface.on_jsdialog(
+        browser,
+        origin_url,
+        dialog_type,
+        message_text,
+        default_prompt_text,
+        callback,
+        &mut suppress,
+    );
+    if !suppress_message.is_null() {
+        unsafe { *suppress_message = suppress.into() };
+    }
+    res.into()
+}
+
+extern "C" fn on_before_unload_dialog<H: JsDialogHandler>(
+    this: *mut cef_jsdialog_handler_t,
+    browser: *mut cef_browser_t,
+    message_text: *const cef_string_t,
+    is_reload: ::std::os::raw::c_int,
+    callback: *mut cef_jsdialog_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let message_text = unsafe { CefString::from_raw(message_text).unwrap_or_default() };
+    let callback = unsafe { JsDialogCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_before_unload_dialog(browser, message_text, is_reload > 0, callback)
+        .into()
+}
+
+extern "C" fn on_reset_dialog_state<H: JsDialogHandler>(
+    this: *mut cef_jsdialog_handler_t,
+    browser: *mut cef_browser_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler.interface.on_reset_dialog_state(browser);
+}
+
+extern "C" fn on_dialog_closed<H: JsDialogHandler>(
+    this: *mut cef_jsdialog_handler_t,
+    browser: *mut cef_browser_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler.interface.on_dialog_closed(browser);
+}
@@ -0,0 +1,166 @@
+use bitflags::bitflags;
+use cef_sys::{cef_browser_t, cef_event_flags_t, cef_key_event_t, cef_keyboard_handler_t, XEvent};
+
+use crate::{rc::RcImpl, Browser, KeyEventType};
+
+bitflags! {
+    /// Modifier keys and mouse buttons held down during an input event. See [cef_event_flags_t]
+    /// for more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EventFlags: u32 {
+        const CAPS_LOCK_ON = cef_event_flags_t::EVENTFLAG_CAPS_LOCK_ON.0;
+        const SHIFT_DOWN = cef_event_flags_t::EVENTFLAG_SHIFT_DOWN.0;
+        const CONTROL_DOWN = cef_event_flags_t::EVENTFLAG_CONTROL_DOWN.0;
+        const ALT_DOWN = cef_event_flags_t::EVENTFLAG_ALT_DOWN.0;
+        const LEFT_MOUSE_BUTTON = cef_event_flags_t::EVENTFLAG_LEFT_MOUSE_BUTTON.0;
+        const MIDDLE_MOUSE_BUTTON = cef_event_flags_t::EVENTFLAG_MIDDLE_MOUSE_BUTTON.0;
+        const RIGHT_MOUSE_BUTTON = cef_event_flags_t::EVENTFLAG_RIGHT_MOUSE_BUTTON.0;
+        /// The command key on macOS.
+        const COMMAND_DOWN = cef_event_flags_t::EVENTFLAG_COMMAND_DOWN.0;
+        const NUM_LOCK_ON = cef_event_flags_t::EVENTFLAG_NUM_LOCK_ON.0;
+        const IS_KEY_PAD = cef_event_flags_t::EVENTFLAG_IS_KEY_PAD.0;
+        const IS_LEFT = cef_event_flags_t::EVENTFLAG_IS_LEFT.0;
+        const IS_RIGHT = cef_event_flags_t::EVENTFLAG_IS_RIGHT.0;
+        const ALTGR_DOWN = cef_event_flags_t::EVENTFLAG_ALTGR_DOWN.0;
+        const IS_REPEAT = cef_event_flags_t::EVENTFLAG_IS_REPEAT.0;
+    }
+}
+
+impl EventFlags {
+    /// The flags as the bit field cef expects.
+    pub(crate) fn into_raw(self) -> cef_event_flags_t {
+        cef_event_flags_t(self.bits())
+    }
+}
+
+/// See [cef_key_event_t] for more documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub type_: KeyEventType,
+    pub modifiers: EventFlags,
+    /// The Windows virtual key code of the key, like `0x57` for `W` or `0x7A` for `F11`. It is
+    /// provided on every platform.
+    pub windows_key_code: i32,
+    /// The platform specific key code.
+    pub native_key_code: i32,
+    /// Whether the event is a system key event, like a key pressed with `Alt` on Windows.
+    pub is_system_key: bool,
+    /// The UTF-16 character generated by the key, including the modifiers.
+    pub character: u16,
+    /// The UTF-16 character generated by the key without the modifiers except `Shift`.
+    pub unmodified_character: u16,
+    /// Whether the focus is currently on an editable field of the page.
+    pub focus_on_editable_field: bool,
+}
+
+impl KeyEvent {
+    /// Returns `true` for key presses, not for key releases or character events.
+    pub fn is_key_down(&self) -> bool {
+        matches!(
+            self.type_,
+            KeyEventType::KEYEVENT_RAWKEYDOWN | KeyEventType::KEYEVENT_KEYDOWN
+        )
+    }
+
+    /// Returns the generated character, if any.
+    pub fn character(&self) -> Option<char> {
+        char::from_u32(self.character as u32).filter(|c| *c != '\0')
+    }
+
+    pub fn into_raw(self) -> cef_key_event_t {
+        cef_key_event_t {
+            type_: self.type_,
+            modifiers: self.modifiers.bits(),
+            windows_key_code: self.windows_key_code,
+            native_key_code: self.native_key_code,
+            is_system_key: self.is_system_key.into(),
+            character: self.character,
+            unmodified_character: self.unmodified_character,
+            focus_on_editable_field: self.focus_on_editable_field.into(),
+        }
+    }
+}
+
+impl From<&cef_key_event_t> for KeyEvent {
+    fn from(raw: &cef_key_event_t) -> Self {
+        Self {
+            type_: raw.type_,
+            modifiers: EventFlags::from_bits_truncate(raw.modifiers),
+            windows_key_code: raw.windows_key_code,
+            native_key_code: raw.native_key_code,
+            is_system_key: raw.is_system_key > 0,
+            character: raw.character,
+            unmodified_character: raw.unmodified_character,
+            focus_on_editable_field: raw.focus_on_editable_field > 0,
+        }
+    }
+}
+
+/// See [cef_keyboard_handler_t] for more documentation.
+///
+/// The methods are called on the UI thread, for windowed and windowless browsers alike.
+pub trait KeyboardHandler: Sized {
+    /// Called before a keyboard event is sent to the page. Return `true` if the event was
+    /// handled and must not reach the page. If the event is a keyboard shortcut that should only
+    /// be handled when the page doesn't handle it, set `is_keyboard_shortcut` to `true` and
+    /// return `false`, then handle it in [KeyboardHandler::on_key_event].
+    fn on_pre_key_event(
+        &self,
+        _browser: Browser,
+        _event: KeyEvent,
+        _is_keyboard_shortcut: &mut bool,
+    ) -> bool {
+        false
+    }
+
+    /// Called after the page has processed a keyboard event, if it didn't handle it. Return
+    /// `true` if the event was handled.
+    fn on_key_event(&self, _browser: Browser, _event: KeyEvent) -> bool {
+        false
+    }
+
+    fn get_raw(&self) -> *mut cef_keyboard_handler_t {
+        let mut object: cef_keyboard_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_pre_key_event = Some(on_pre_key_event::<Self>);
+        object.on_key_event = Some(on_key_event::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl KeyboardHandler for () {}
+
+extern "C" fn on_pre_key_event<H: KeyboardHandler>(
+    this: *mut cef_keyboard_handler_t,
+    browser: *mut cef_browser_t,
+    event: *const cef_key_event_t,
+    _os_event: *mut XEvent,
+    is_keyboard_shortcut: *mut ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let event = KeyEvent::from(unsafe { &*event });
+    let mut shortcut = false;
+
+    let res = handler
+        .interface
+        .on_pre_key_event(browser, event, &mut shortcut);
+    if !is_keyboard_shortcut.is_null() {
+        unsafe { *is_keyboard_shortcut = shortcut.into() };
+    }
+    res.into()
+}
+
+extern "C" fn on_key_event<H: KeyboardHandler>(
+    this: *mut cef_keyboard_handler_t,
+    browser: *mut cef_browser_t,
+    event: *const cef_key_event_t,
+    _os_event: *mut XEvent,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let event = KeyEvent::from(unsafe { &*event });
+
+    handler.interface.on_key_event(browser, event).into()
+}
@@ -92,8 +92,41 @@ pub mod browser_process_handler;
 pub mod render_process_handler;
 pub mod load_handler;
 pub mod life_span_handler;
+pub mod display_handler;
+pub mod keyboard_handler;
+pub mod focus_handler;
+pub mod context_menu_handler;
+pub mod menu_model;
+pub mod jsdialog_handler;
+pub mod dialog_handler;
+pub mod permission_handler;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub mod console_log;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub mod chromium_log;
 pub mod frame;
 pub mod client;
+pub mod download_handler;
+pub mod download_item;
+pub mod callback;
+pub mod request;
+pub mod response;
+pub mod post_data;
+pub mod resource_handler;
+pub mod scheme;
+pub mod resource_bundle_handler;
+pub mod static_asset;
+pub mod request_handler;
+pub mod resource_request_handler;
+pub mod response_filter;
+pub mod mock_network;
+pub mod request_context;
+pub mod preference;
+pub mod proxy;
+pub mod url_request;
+pub mod cookie;
+pub mod cookie_access_filter;
+pub mod ssl_info;
 mod command_line;
 mod panel;
 pub mod rc;
@@ -122,3 +155,22 @@ pub type DragOperationsMask = cef_sys::cef_drag_operations_mask_t;
 pub type LogSeverity = cef_sys::cef_log_severity_t;
 pub type LogItems = cef_sys::cef_log_items_t;
 pub type State = cef_sys::cef_state_t;
+pub type DownloadInterruptReason = cef_sys::cef_download_interrupt_reason_t;
+pub type WindowOpenDisposition = cef_sys::cef_window_open_disposition_t;
+pub type TerminationStatus = cef_sys::cef_termination_status_t;
+pub type ReferrerPolicy = cef_sys::cef_referrer_policy_t;
+pub type ResourceType = cef_sys::cef_resource_type_t;
+pub type UrlRequestStatus = cef_sys::cef_urlrequest_status_t;
+pub type ResponseFilterStatus = cef_sys::cef_response_filter_status_t;
+pub type CookiePriority = cef_sys::cef_cookie_priority_t;
+pub type ValueType = cef_sys::cef_value_type_t;
+pub type PreferencesType = cef_sys::cef_preferences_type_t;
+pub type CursorType = cef_sys::cef_cursor_type_t;
+pub type KeyEventType = cef_sys::cef_key_event_type_t;
+pub type FocusSource = cef_sys::cef_focus_source_t;
+pub type MenuItemType = cef_sys::cef_menu_item_type_t;
+pub type MenuId = cef_sys::cef_menu_id_t;
+pub type ContextMenuMediaType = cef_sys::cef_context_menu_media_type_t;
+pub type JsDialogType = cef_sys::cef_jsdialog_type_t;
+pub type FileDialogMode = cef_sys::cef_file_dialog_mode_t;
+pub type PermissionRequestResult = cef_sys::cef_permission_request_result_t;
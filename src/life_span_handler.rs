@@ -1,13 +1,92 @@
+use std::ptr::null_mut;
+
 use crate::{
-    rc::RcImpl, Browser,
+    client::Client,
+    frame::Frame,
+    rc::{Rc, RcImpl},
+    string::CefString,
+    values::DictionaryValue,
+    Browser, BrowserSettings, WindowInfo, WindowOpenDisposition,
 };
 use cef_sys::{
-    cef_browser_t,
-    cef_life_span_handler_t,
+    cef_browser_settings_t, cef_browser_t, cef_client_t, cef_dictionary_value_t, cef_frame_t,
+    cef_life_span_handler_t, cef_popup_features_t, cef_string_t, cef_window_info_t,
+    cef_window_open_disposition_t,
 };
 
-/// See [cef_load_handler_t] for more documentation.
+/// The features requested with `window.open`. See [cef_popup_features_t] for more
+/// documentation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PopupFeatures {
+    pub x: Option<i32>,
+    pub y: Option<i32>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    /// Whether a popup window was requested instead of a tab.
+    pub is_popup: bool,
+}
+
+impl From<&cef_popup_features_t> for PopupFeatures {
+    fn from(raw: &cef_popup_features_t) -> Self {
+        Self {
+            x: (raw.xSet > 0).then_some(raw.x),
+            y: (raw.ySet > 0).then_some(raw.y),
+            width: (raw.widthSet > 0).then_some(raw.width),
+            height: (raw.heightSet > 0).then_some(raw.height),
+            is_popup: raw.isPopup > 0,
+        }
+    }
+}
+
+/// The options of the popup browser created after [LifeSpanHandler::on_before_popup]. They
+/// default to the values of the source browser.
+#[derive(Debug)]
+pub struct PopupOptions {
+    /// Changes are ignored if the source browser is hosted in a [BrowserView](crate::BrowserView).
+    pub window_info: WindowInfo,
+    pub settings: BrowserSettings,
+    /// Extra information passed to
+    /// [RenderProcessHandler::on_browser_created](crate::render_process_handler::RenderProcessHandler)
+    /// in the render process. The existing value is read-only but may be replaced.
+    pub extra_info: Option<DictionaryValue>,
+    /// Whether the opener must not have scripting access to the popup. If `false` the popup is
+    /// created in the same render process as the opener.
+    pub no_javascript_access: bool,
+    client: Option<*mut cef_client_t>,
+}
+
+impl PopupOptions {
+    /// Use `client` for the popup browser instead of the client of the source browser.
+    pub fn set_client<C: Client>(&mut self, client: C) {
+        if let Some(previous) = self.client.replace(client.into_raw()) {
+            unsafe { (*previous).base.release() };
+        }
+    }
+}
+
+/// See [cef_life_span_handler_t] for more documentation.
 pub trait LifeSpanHandler: Sized {
+    /// Called on the UI thread before a popup browser is created, like for `window.open` or
+    /// links with `target="_blank"`. `target_frame_name` is the name of the target window, if
+    /// any. `user_gesture` is `true` if the popup was opened by a user action like a click.
+    ///
+    /// Return `true` to cancel the popup, for instance to load `target_url` in a tab of the
+    /// application instead. Return `false` to create it with `options`, which may be modified.
+    #[allow(clippy::too_many_arguments)]
+    fn on_before_popup(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _target_url: CefString,
+        _target_frame_name: Option<CefString>,
+        _target_disposition: WindowOpenDisposition,
+        _user_gesture: bool,
+        _popup_features: PopupFeatures,
+        _options: &mut PopupOptions,
+    ) -> bool {
+        false
+    }
+
     // type Client: Client;
     // fn on_before_dev_tools_popup(
     //     &self,
@@ -28,6 +107,7 @@ pub trait LifeSpanHandler: Sized {
     fn get_raw(&self) -> *mut cef_life_span_handler_t {
         let mut object: cef_life_span_handler_t = unsafe { std::mem::zeroed() };
 
+        object.on_before_popup = Some(on_before_popup::<Self>);
         // object.on_before_dev_tools_popup = Some(on_before_dev_tools_popup::<Self>);
         object.on_after_created = Some(on_after_created::<Self>);
         object.do_close = Some(do_close::<Self>);
@@ -42,6 +122,78 @@ impl LifeSpanHandler for () {
     fn on_before_close(&self, _browser: Browser) {}
 }
 
+#[allow(clippy::too_many_arguments)]
+extern "C" fn on_before_popup<L: LifeSpanHandler>(
+    this: *mut cef_life_span_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    target_url: *const cef_string_t,
+    target_frame_name: *const cef_string_t,
+    target_disposition: cef_window_open_disposition_t,
+    user_gesture: ::std::os::raw::c_int,
+    popup_features: *const cef_popup_features_t,
+    window_info: *mut cef_window_info_t,
+    client: *mut *mut cef_client_t,
+    settings: *mut cef_browser_settings_t,
+    extra_info: *mut *mut cef_dictionary_value_t,
+    no_javascript_access: *mut ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &L> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let target_url = unsafe { CefString::from_raw(target_url).unwrap_or_default() };
+    let target_frame_name = unsafe { CefString::from_raw(target_frame_name).ok() }
+        .filter(|name| !name.to_string().is_empty());
+    let popup_features = unsafe { popup_features.as_ref() }
+        .map(PopupFeatures::from)
+        .unwrap_or_default();
+
+    // The references to the original client and extra info are owned by this side, whether they
+    // are replaced or not.
+    let original_extra_info = unsafe { *extra_info };
+    let mut options = PopupOptions {
+        window_info: WindowInfo::from_mut_ptr(window_info),
+        settings: BrowserSettings::from(unsafe { &*settings }),
+        extra_info: (!original_extra_info.is_null())
+            .then(|| unsafe { DictionaryValue::from_raw(original_extra_info) }),
+        no_javascript_access: unsafe { *no_javascript_access } > 0,
+        client: None,
+    };
+
+    let cancel = handler.interface.on_before_popup(
+        browser,
+        frame,
+        target_url,
+        target_frame_name,
+        target_disposition,
+        user_gesture > 0,
+        popup_features,
+        &mut options,
+    );
+
+    unsafe {
+        options.window_info.set_raw(&mut *window_info);
+        options.settings.set_raw(&mut *settings);
+        *no_javascript_access = options.no_javascript_access.into();
+
+        match options.extra_info {
+            Some(value) if value.0.get_raw() == original_extra_info => {}
+            Some(value) => *extra_info = value.into_raw(),
+            None => *extra_info = null_mut(),
+        }
+
+        let original_client = *client;
+        if let Some(replacement) = options.client {
+            *client = replacement;
+        }
+        if !original_client.is_null() {
+            (*original_client).base.release();
+        }
+    }
+
+    cancel.into()
+}
+
 // extern "C" fn on_before_dev_tools_popup<L: LifeSpanHandler>(
 //     this: *mut cef_life_span_handler_t,
 //     browser: *mut cef_browser_t,
@@ -0,0 +1,508 @@
+use std::os::raw::c_int;
+
+use cef_sys::cef_menu_model_t;
+
+use crate::{
+    string::{CefString, CefStringError},
+    wrapper, MenuItemType,
+};
+
+wrapper!(
+    #[doc = "See [cef_menu_model_t] for more documentation."]
+    #[doc = ""]
+    #[doc = "The methods must be called on the browser process UI thread."]
+    #[derive(Debug, Clone)]
+    pub struct MenuModel(cef_menu_model_t);
+);
+
+impl MenuModel {
+    /// Returns `true` if this menu is a submenu.
+    pub fn is_sub_menu(&self) -> bool {
+        self.0
+            .is_sub_menu
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Remove all the items, like the default items of a context menu.
+    pub fn clear(&self) -> bool {
+        self.0
+            .clear
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the number of items, including separators.
+    pub fn get_count(&self) -> usize {
+        self.0
+            .get_count
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn add_separator(&self) -> bool {
+        self.0
+            .add_separator
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Add a command item. Custom commands should use ids between
+    /// [MenuId::MENU_ID_USER_FIRST](crate::MenuId::MENU_ID_USER_FIRST) and
+    /// [MenuId::MENU_ID_USER_LAST](crate::MenuId::MENU_ID_USER_LAST).
+    pub fn add_item(&self, command_id: i32, label: CefString) -> bool {
+        self.0
+            .add_item
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn add_check_item(&self, command_id: i32, label: CefString) -> bool {
+        self.0
+            .add_check_item
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Add a radio item. Only one item of the group `group_id` can be checked at a time.
+    pub fn add_radio_item(&self, command_id: i32, label: CefString, group_id: i32) -> bool {
+        self.0
+            .add_radio_item
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, &label.get_raw(), group_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Add a submenu and return it so items can be added to it.
+    pub fn add_sub_menu(&self, command_id: i32, label: CefString) -> Option<MenuModel> {
+        let menu = self
+            .0
+            .add_sub_menu
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, &label.get_raw()) })?;
+
+        (!menu.is_null()).then(|| unsafe { MenuModel::from_raw(menu) })
+    }
+
+    pub fn insert_separator_at(&self, index: usize) -> bool {
+        self.0
+            .insert_separator_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn insert_item_at(&self, index: usize, command_id: i32, label: CefString) -> bool {
+        self.0
+            .insert_item_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, command_id, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn insert_check_item_at(&self, index: usize, command_id: i32, label: CefString) -> bool {
+        self.0
+            .insert_check_item_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, command_id, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn insert_radio_item_at(
+        &self,
+        index: usize,
+        command_id: i32,
+        label: CefString,
+        group_id: i32,
+    ) -> bool {
+        self.0
+            .insert_radio_item_at
+            .map(|f| unsafe {
+                f(
+                    self.0.get_raw(),
+                    index,
+                    command_id,
+                    &label.get_raw(),
+                    group_id,
+                )
+            } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn insert_sub_menu_at(
+        &self,
+        index: usize,
+        command_id: i32,
+        label: CefString,
+    ) -> Option<MenuModel> {
+        let menu = self
+            .0
+            .insert_sub_menu_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, command_id, &label.get_raw()) })?;
+
+        (!menu.is_null()).then(|| unsafe { MenuModel::from_raw(menu) })
+    }
+
+    /// Remove the item with the given command id.
+    pub fn remove(&self, command_id: i32) -> bool {
+        self.0
+            .remove
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn remove_at(&self, index: usize) -> bool {
+        self.0
+            .remove_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the index of the item with the given command id.
+    pub fn get_index_of(&self, command_id: i32) -> Option<usize> {
+        let index = self
+            .0
+            .get_index_of
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) })?;
+
+        (index >= 0).then_some(index as usize)
+    }
+
+    /// Returns the command id at `index`, or `None` for separators and invalid indexes.
+    pub fn get_command_id_at(&self, index: usize) -> Option<i32> {
+        let command_id = self
+            .0
+            .get_command_id_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) })?;
+
+        (command_id != -1).then_some(command_id)
+    }
+
+    pub fn set_command_id_at(&self, index: usize, command_id: i32) -> bool {
+        self.0
+            .set_command_id_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_label(&self, command_id: i32) -> Result<CefString, CefStringError> {
+        let get_label = self.0.get_label.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_label(self.0.get_raw(), command_id)) }
+    }
+
+    pub fn get_label_at(&self, index: usize) -> Result<CefString, CefStringError> {
+        let get_label_at = self.0.get_label_at.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_label_at(self.0.get_raw(), index)) }
+    }
+
+    pub fn set_label(&self, command_id: i32, label: CefString) -> bool {
+        self.0
+            .set_label
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_label_at(&self, index: usize, label: CefString) -> bool {
+        self.0
+            .set_label_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, &label.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_type(&self, command_id: i32) -> MenuItemType {
+        self.0
+            .get_type
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) })
+            .unwrap_or(MenuItemType::MENUITEMTYPE_NONE)
+    }
+
+    pub fn get_type_at(&self, index: usize) -> MenuItemType {
+        self.0
+            .get_type_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) })
+            .unwrap_or(MenuItemType::MENUITEMTYPE_NONE)
+    }
+
+    /// Returns the group id of a radio item, or `None` if the item has no group.
+    pub fn get_group_id(&self, command_id: i32) -> Option<i32> {
+        let group_id = self
+            .0
+            .get_group_id
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) })?;
+
+        (group_id != -1).then_some(group_id)
+    }
+
+    pub fn get_group_id_at(&self, index: usize) -> Option<i32> {
+        let group_id = self
+            .0
+            .get_group_id_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) })?;
+
+        (group_id != -1).then_some(group_id)
+    }
+
+    pub fn set_group_id(&self, command_id: i32, group_id: i32) -> bool {
+        self.0
+            .set_group_id
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, group_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_group_id_at(&self, index: usize, group_id: i32) -> bool {
+        self.0
+            .set_group_id_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, group_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the submenu of the item with the given command id, if it is a submenu.
+    pub fn get_sub_menu(&self, command_id: i32) -> Option<MenuModel> {
+        let menu = self
+            .0
+            .get_sub_menu
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) })?;
+
+        (!menu.is_null()).then(|| unsafe { MenuModel::from_raw(menu) })
+    }
+
+    pub fn get_sub_menu_at(&self, index: usize) -> Option<MenuModel> {
+        let menu = self
+            .0
+            .get_sub_menu_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) })?;
+
+        (!menu.is_null()).then(|| unsafe { MenuModel::from_raw(menu) })
+    }
+
+    pub fn is_visible(&self, command_id: i32) -> bool {
+        self.0
+            .is_visible
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_visible_at(&self, index: usize) -> bool {
+        self.0
+            .is_visible_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_visible(&self, command_id: i32, visible: bool) -> bool {
+        self.0
+            .set_visible
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, visible.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_visible_at(&self, index: usize, visible: bool) -> bool {
+        self.0
+            .set_visible_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, visible.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_enabled(&self, command_id: i32) -> bool {
+        self.0
+            .is_enabled
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_enabled_at(&self, index: usize) -> bool {
+        self.0
+            .is_enabled_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_enabled(&self, command_id: i32, enabled: bool) -> bool {
+        self.0
+            .set_enabled
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, enabled.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_enabled_at(&self, index: usize, enabled: bool) -> bool {
+        self.0
+            .set_enabled_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, enabled.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the check or radio item with the given command id is checked.
+    pub fn is_checked(&self, command_id: i32) -> bool {
+        self.0
+            .is_checked
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_checked_at(&self, index: usize) -> bool {
+        self.0
+            .is_checked_at
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_checked(&self, command_id: i32, checked: bool) -> bool {
+        self.0
+            .set_checked
+            .map(|f| unsafe { f(self.0.get_raw(), command_id, checked.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_checked_at(&self, index: usize, checked: bool) -> bool {
+        self.0
+            .set_checked_at
+            .map(|f| unsafe { f(self.0.get_raw(), index, checked.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the keyboard accelerator of the item with the given command id, if any.
+    pub fn get_accelerator(&self, command_id: i32) -> Option<Accelerator> {
+        let get_accelerator = self.0.get_accelerator?;
+        let mut raw = [0 as c_int; 4];
+        let [key_code, shift, ctrl, alt] = &mut raw;
+
+        let found =
+            unsafe { get_accelerator(self.0.get_raw(), command_id, key_code, shift, ctrl, alt) };
+        (found > 0).then(|| Accelerator::from_raw(raw))
+    }
+
+    pub fn get_accelerator_at(&self, index: usize) -> Option<Accelerator> {
+        let get_accelerator_at = self.0.get_accelerator_at?;
+        let mut raw = [0 as c_int; 4];
+        let [key_code, shift, ctrl, alt] = &mut raw;
+
+        let found =
+            unsafe { get_accelerator_at(self.0.get_raw(), index, key_code, shift, ctrl, alt) };
+        (found > 0).then(|| Accelerator::from_raw(raw))
+    }
+
+    pub fn set_accelerator(&self, command_id: i32, accelerator: Accelerator) -> bool {
+        let Accelerator {
+            key_code,
+            shift_pressed,
+            ctrl_pressed,
+            alt_pressed,
+        } = accelerator;
+
+        self.0
+            .set_accelerator
+            .map(|f| unsafe {
+                f(
+                    self.0.get_raw(),
+                    command_id,
+                    key_code,
+                    shift_pressed.into(),
+                    ctrl_pressed.into(),
+                    alt_pressed.into(),
+                )
+            } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn remove_accelerator(&self, command_id: i32) -> bool {
+        self.0
+            .remove_accelerator
+            .map(|f| unsafe { f(self.0.get_raw(), command_id) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns a snapshot of the items of this menu and its submenus. Hidden items are skipped.
+    ///
+    /// This is useful to draw the menu in [ContextMenuHandler::run_context_menu] for windowless
+    /// browsers. Pass the [MenuItem::command_id] of the selected item to
+    /// [RunContextMenuCallback::cont].
+    ///
+    /// [ContextMenuHandler::run_context_menu]: crate::context_menu_handler::ContextMenuHandler::run_context_menu
+    /// [RunContextMenuCallback::cont]: crate::context_menu_handler::RunContextMenuCallback::cont
+    pub fn items(&self) -> Vec<MenuItem> {
+        (0..self.get_count())
+            .filter(|index| self.is_visible_at(*index))
+            .map(|index| {
+                let type_ = self.get_type_at(index);
+                let sub_menu = match type_ {
+                    MenuItemType::MENUITEMTYPE_SUBMENU => self
+                        .get_sub_menu_at(index)
+                        .map(|menu| menu.items())
+                        .unwrap_or_default(),
+                    _ => Vec::new(),
+                };
+
+                MenuItem {
+                    command_id: self.get_command_id_at(index).unwrap_or(-1),
+                    type_,
+                    label: self
+                        .get_label_at(index)
+                        .map(|label| label.to_string())
+                        .unwrap_or_default(),
+                    enabled: self.is_enabled_at(index),
+                    checked: self.is_checked_at(index),
+                    group_id: self.get_group_id_at(index),
+                    accelerator: self.get_accelerator_at(index),
+                    sub_menu,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A keyboard shortcut displayed next to a menu item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Accelerator {
+    /// The Windows virtual key code, like in [KeyEvent::windows_key_code].
+    ///
+    /// [KeyEvent::windows_key_code]: crate::keyboard_handler::KeyEvent::windows_key_code
+    pub key_code: i32,
+    pub shift_pressed: bool,
+    pub ctrl_pressed: bool,
+    pub alt_pressed: bool,
+}
+
+impl Accelerator {
+    fn from_raw([key_code, shift, ctrl, alt]: [c_int; 4]) -> Self {
+        Self {
+            key_code,
+            shift_pressed: shift > 0,
+            ctrl_pressed: ctrl > 0,
+            alt_pressed: alt > 0,
+        }
+    }
+}
+
+/// An item of a [MenuModel], see [MenuModel::items].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuItem {
+    /// The command id, or -1 for separators.
+    pub command_id: i32,
+    pub type_: MenuItemType,
+    /// The label. A `&` marks the following character as mnemonic and `&&` is a literal `&`.
+    pub label: String,
+    pub enabled: bool,
+    /// Whether a check or radio item is checked.
+    pub checked: bool,
+    /// The group of a radio item.
+    pub group_id: Option<i32>,
+    pub accelerator: Option<Accelerator>,
+    /// The items of a submenu.
+    pub sub_menu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn is_separator(&self) -> bool {
+        self.type_ == MenuItemType::MENUITEMTYPE_SEPARATOR
+    }
+
+    /// Returns the label without the mnemonic markers.
+    pub fn display_label(&self) -> String {
+        let mut label = String::with_capacity(self.label.len());
+        let mut chars = self.label.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '&' => label.extend(chars.next()),
+                c => label.push(c),
+            }
+        }
+        label
+    }
+}
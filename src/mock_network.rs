@@ -0,0 +1,330 @@
+//! Mock network module
+//!
+//! Serve requests from memory instead of the network, mostly useful for tests that must run
+//! offline.
+//!
+//! - [`Responder`] lets a closure answer requests with a [`MockResponse`].
+//! - [`MockNetwork`] maps URL patterns to canned responses, answers every other http(s) request
+//!   with a fallback response and records every request it sees.
+//!
+//! Both implement [`RequestHandler`] and [`ResourceRequestHandler`], so they can be returned
+//! from [`Client::get_request_handler`](crate::client::Client::get_request_handler) directly:
+//!
+//! ```no_run
+//! use cef::mock_network::{MockNetwork, MockResponse};
+//!
+//! let network = MockNetwork::new();
+//! network.mock("https://example.com/", MockResponse::html("<h1>Hello</h1>"));
+//! network.mock("https://example.com/api/*", MockResponse::json(r#"{"ok":true}"#));
+//!
+//! // Load a page with the network as request handler, then:
+//! for request in network.requests() {
+//!     println!("{} {}", request.method, request.url);
+//! }
+//! ```
+
+use std::sync::Mutex;
+
+use crate::{
+    callback::Callback,
+    frame::Frame,
+    request::Request,
+    request_handler::RequestHandler,
+    resource_handler::{OpenResult, ReadResult, ResourceHandler, ResourceSkipCallback, SkipResult},
+    resource_request_handler::ResourceRequestHandler,
+    response::Response,
+    string::CefString,
+    utils::matches_pattern,
+    Browser,
+};
+
+/// A response served from memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    pub status: i32,
+    pub status_text: String,
+    pub mime_type: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Create a response with the given status, MIME type and body.
+    pub fn new(status: i32, mime_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            status_text: status_text(status).to_string(),
+            mime_type: mime_type.to_string(),
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response with a `text/html` body.
+    pub fn html(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(200, "text/html", body)
+    }
+
+    /// A `200 OK` response with an `application/json` body.
+    pub fn json(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(200, "application/json", body)
+    }
+
+    /// An empty response with the given status.
+    pub fn status(status: i32) -> Self {
+        Self::new(status, "text/plain", Vec::new())
+    }
+
+    /// Append a header. A header name can be added multiple times.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+fn status_text(status: i32) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+/// The [ResourceHandler] serving a [MockResponse].
+#[derive(Debug)]
+pub struct MockResourceHandler {
+    response: MockResponse,
+    offset: usize,
+}
+
+impl MockResourceHandler {
+    pub fn new(response: MockResponse) -> Self {
+        Self {
+            response,
+            offset: 0,
+        }
+    }
+}
+
+impl ResourceHandler for MockResourceHandler {
+    fn open(&mut self, _request: Request, _callback: Callback) -> OpenResult {
+        OpenResult::Handle
+    }
+
+    fn get_response_headers(
+        &mut self,
+        response: Response,
+        _redirect_url: &mut Option<CefString>,
+    ) -> Option<u64> {
+        let mock = &self.response;
+        response.set_status(mock.status);
+        response.set_status_text(CefString::new(&mock.status_text));
+        response.set_mime_type(CefString::new(&mock.mime_type));
+        response.set_header_map(
+            mock.headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect(),
+        );
+
+        Some(mock.body.len() as u64)
+    }
+
+    fn skip(&mut self, bytes_to_skip: u64, _callback: ResourceSkipCallback) -> SkipResult {
+        let remaining = self.response.body.len() - self.offset;
+        let skipped = (bytes_to_skip as usize).min(remaining);
+        self.offset += skipped;
+        SkipResult::Skipped(skipped as u64)
+    }
+
+    fn read(&mut self, data_out: &mut [u8]) -> ReadResult {
+        let remaining = &self.response.body[self.offset..];
+        let len = data_out.len().min(remaining.len());
+        if len == 0 {
+            return ReadResult::Complete;
+        }
+
+        data_out[..len].copy_from_slice(&remaining[..len]);
+        self.offset += len;
+        ReadResult::Read(len)
+    }
+}
+
+/// Answer requests with a closure. Requests the closure returns `None` for are loaded normally.
+#[derive(Debug, Clone)]
+pub struct Responder<F>(pub F);
+
+impl<F: Fn(&Request) -> Option<MockResponse>> ResourceRequestHandler for Responder<F> {
+    type CookieAccessFilter = ();
+    type ResourceHandler = MockResourceHandler;
+    type ResponseFilter = ();
+
+    fn get_resource_handler(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        request: Request,
+    ) -> Option<Self::ResourceHandler> {
+        (self.0)(&request).map(MockResourceHandler::new)
+    }
+}
+
+impl<F: Fn(&Request) -> Option<MockResponse>> RequestHandler for Responder<F> {
+    type ResourceRequestHandler = Self;
+
+    fn get_resource_request_handler(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _request: Request,
+        _is_navigation: bool,
+        _is_download: bool,
+        _request_initiator: CefString,
+        _disable_default_handling: &mut bool,
+    ) -> Option<&Self::ResourceRequestHandler> {
+        Some(self)
+    }
+}
+
+/// A request seen by [MockNetwork].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// The bytes elements of the post data.
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        Self {
+            method: request.get_method().unwrap_or_default().to_string(),
+            url: request.get_url().unwrap_or_default().to_string(),
+            headers: request
+                .get_header_map()
+                .to_vec()
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: request
+                .get_post_data()
+                .map(|p| p.to_bytes())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Serve every http(s) request from canned responses so pages load without network access.
+///
+/// URL patterns are matched against the full URL and may contain `*` wildcards matching any
+/// sequence of characters. Routes added later take precedence. Requests that match no route are
+/// answered with the fallback response, a `404 Not Found` by default. Other schemes are loaded
+/// normally.
+#[derive(Debug)]
+pub struct MockNetwork {
+    routes: Mutex<Vec<(String, MockResponse)>>,
+    fallback: Mutex<MockResponse>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl Default for MockNetwork {
+    fn default() -> Self {
+        Self {
+            routes: Mutex::new(Vec::new()),
+            fallback: Mutex::new(MockResponse::status(404)),
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl MockNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests whose URL matches `pattern` with `response`.
+    pub fn mock(&self, pattern: &str, response: MockResponse) -> &Self {
+        self.routes
+            .lock()
+            .unwrap()
+            .push((pattern.to_string(), response));
+        self
+    }
+
+    /// Answer requests that match no route with `response`.
+    pub fn set_fallback(&self, response: MockResponse) -> &Self {
+        *self.fallback.lock().unwrap() = response;
+        self
+    }
+
+    /// Returns every request seen so far in the order they were made.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn clear_requests(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+
+    /// Returns the response for `url`.
+    pub fn response_for(&self, url: &str) -> MockResponse {
+        self.routes
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|(pattern, _)| matches_pattern(pattern, url))
+            .map(|(_, response)| response.clone())
+            .unwrap_or_else(|| self.fallback.lock().unwrap().clone())
+    }
+}
+
+impl ResourceRequestHandler for MockNetwork {
+    type CookieAccessFilter = ();
+    type ResourceHandler = MockResourceHandler;
+    type ResponseFilter = ();
+
+    fn get_resource_handler(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        request: Request,
+    ) -> Option<Self::ResourceHandler> {
+        let recorded = RecordedRequest::new(&request);
+        if !recorded.url.starts_with("http://") && !recorded.url.starts_with("https://") {
+            return None;
+        }
+
+        let response = self.response_for(&recorded.url);
+        self.requests.lock().unwrap().push(recorded);
+        Some(MockResourceHandler::new(response))
+    }
+}
+
+impl RequestHandler for MockNetwork {
+    type ResourceRequestHandler = Self;
+
+    fn get_resource_request_handler(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _request: Request,
+        _is_navigation: bool,
+        _is_download: bool,
+        _request_initiator: CefString,
+        _disable_default_handling: &mut bool,
+    ) -> Option<&Self::ResourceRequestHandler> {
+        Some(self)
+    }
+}
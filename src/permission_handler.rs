@@ -0,0 +1,474 @@
+use std::sync::Mutex;
+
+use bitflags::bitflags;
+use cef_sys::{
+    cef_browser_t, cef_frame_t, cef_media_access_callback_t, cef_media_access_permission_types_t,
+    cef_permission_handler_t, cef_permission_prompt_callback_t, cef_permission_request_result_t,
+    cef_permission_request_types_t, cef_string_t,
+};
+
+use crate::{
+    frame::Frame, rc::RcImpl, string::CefString, utils::matches_pattern, wrapper, Browser,
+    PermissionRequestResult,
+};
+
+bitflags! {
+    /// Media access permissions requested by `getUserMedia` or `getDisplayMedia`. See
+    /// [cef_media_access_permission_types_t] for more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MediaAccessPermissions: u32 {
+        /// Microphone access.
+        const DEVICE_AUDIO_CAPTURE = cef_media_access_permission_types_t::CEF_MEDIA_PERMISSION_DEVICE_AUDIO_CAPTURE as u32;
+        /// Camera access.
+        const DEVICE_VIDEO_CAPTURE = cef_media_access_permission_types_t::CEF_MEDIA_PERMISSION_DEVICE_VIDEO_CAPTURE as u32;
+        /// Desktop audio capture.
+        const DESKTOP_AUDIO_CAPTURE = cef_media_access_permission_types_t::CEF_MEDIA_PERMISSION_DESKTOP_AUDIO_CAPTURE as u32;
+        /// Desktop and window capture.
+        const DESKTOP_VIDEO_CAPTURE = cef_media_access_permission_types_t::CEF_MEDIA_PERMISSION_DESKTOP_VIDEO_CAPTURE as u32;
+    }
+
+    /// Permissions requested with a permission prompt. See [cef_permission_request_types_t] for
+    /// more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PermissionRequestTypes: u32 {
+        const ACCESSIBILITY_EVENTS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_ACCESSIBILITY_EVENTS as u32;
+        const AR_SESSION = cef_permission_request_types_t::CEF_PERMISSION_TYPE_AR_SESSION as u32;
+        const CAMERA_PAN_TILT_ZOOM = cef_permission_request_types_t::CEF_PERMISSION_TYPE_CAMERA_PAN_TILT_ZOOM as u32;
+        const CAMERA_STREAM = cef_permission_request_types_t::CEF_PERMISSION_TYPE_CAMERA_STREAM as u32;
+        const CAPTURED_SURFACE_CONTROL = cef_permission_request_types_t::CEF_PERMISSION_TYPE_CAPTURED_SURFACE_CONTROL as u32;
+        const CLIPBOARD = cef_permission_request_types_t::CEF_PERMISSION_TYPE_CLIPBOARD as u32;
+        const TOP_LEVEL_STORAGE_ACCESS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_TOP_LEVEL_STORAGE_ACCESS as u32;
+        const DISK_QUOTA = cef_permission_request_types_t::CEF_PERMISSION_TYPE_DISK_QUOTA as u32;
+        const LOCAL_FONTS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_LOCAL_FONTS as u32;
+        const GEOLOCATION = cef_permission_request_types_t::CEF_PERMISSION_TYPE_GEOLOCATION as u32;
+        const IDENTITY_PROVIDER = cef_permission_request_types_t::CEF_PERMISSION_TYPE_IDENTITY_PROVIDER as u32;
+        const IDLE_DETECTION = cef_permission_request_types_t::CEF_PERMISSION_TYPE_IDLE_DETECTION as u32;
+        const MIC_STREAM = cef_permission_request_types_t::CEF_PERMISSION_TYPE_MIC_STREAM as u32;
+        const MIDI_SYSEX = cef_permission_request_types_t::CEF_PERMISSION_TYPE_MIDI_SYSEX as u32;
+        const MULTIPLE_DOWNLOADS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_MULTIPLE_DOWNLOADS as u32;
+        const NOTIFICATIONS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_NOTIFICATIONS as u32;
+        const PROTECTED_MEDIA_IDENTIFIER = cef_permission_request_types_t::CEF_PERMISSION_TYPE_PROTECTED_MEDIA_IDENTIFIER as u32;
+        const REGISTER_PROTOCOL_HANDLER = cef_permission_request_types_t::CEF_PERMISSION_TYPE_REGISTER_PROTOCOL_HANDLER as u32;
+        const STORAGE_ACCESS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_STORAGE_ACCESS as u32;
+        const VR_SESSION = cef_permission_request_types_t::CEF_PERMISSION_TYPE_VR_SESSION as u32;
+        const WINDOW_MANAGEMENT = cef_permission_request_types_t::CEF_PERMISSION_TYPE_WINDOW_MANAGEMENT as u32;
+        const FILE_SYSTEM_ACCESS = cef_permission_request_types_t::CEF_PERMISSION_TYPE_FILE_SYSTEM_ACCESS as u32;
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_media_access_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct MediaAccessCallback(cef_media_access_callback_t);
+    pub fn cancel(&self);
+);
+
+impl MediaAccessCallback {
+    /// Allow `allowed_permissions` and deny the others. For `getUserMedia` requests it must be
+    /// empty or equal to the requested permissions.
+    pub fn cont(&self, allowed_permissions: MediaAccessPermissions) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), allowed_permissions.bits()) };
+        }
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_permission_prompt_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct PermissionPromptCallback(cef_permission_prompt_callback_t);
+);
+
+impl PermissionPromptCallback {
+    /// Complete the prompt with `result`.
+    pub fn cont(&self, result: PermissionRequestResult) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), result) };
+        }
+    }
+}
+
+/// See [cef_permission_handler_t] for more documentation.
+///
+/// The methods are called on the browser process UI thread. Without a handler, cef denies the
+/// requests.
+pub trait PermissionHandler: Sized {
+    /// Called when a page requests access to the camera, microphone or screen. Return `true`
+    /// and call [MediaAccessCallback::cont] or [MediaAccessCallback::cancel], synchronously or
+    /// from any thread. Return `false` for the default behavior.
+    fn on_request_media_access_permission(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _requesting_origin: CefString,
+        _requested_permissions: MediaAccessPermissions,
+        _callback: MediaAccessCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called when a page requests permissions like geolocation or notifications. `prompt_id`
+    /// identifies the prompt in [PermissionHandler::on_dismiss_permission_prompt]. Return
+    /// `true` and call [PermissionPromptCallback::cont], synchronously or from any thread.
+    /// Return `false` for the default behavior.
+    fn on_show_permission_prompt(
+        &self,
+        _browser: Browser,
+        _prompt_id: u64,
+        _requesting_origin: CefString,
+        _requested_permissions: PermissionRequestTypes,
+        _callback: PermissionPromptCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called when a permission prompt is dismissed. `result` is
+    /// [PermissionRequestResult::CEF_PERMISSION_RESULT_IGNORE] if it was dismissed without
+    /// [PermissionPromptCallback::cont] being called, like when the page navigates away.
+    fn on_dismiss_permission_prompt(
+        &self,
+        _browser: Browser,
+        _prompt_id: u64,
+        _result: PermissionRequestResult,
+    ) {
+    }
+
+    fn get_raw(&self) -> *mut cef_permission_handler_t {
+        let mut object: cef_permission_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_request_media_access_permission =
+            Some(on_request_media_access_permission::<Self>);
+        object.on_show_permission_prompt = Some(on_show_permission_prompt::<Self>);
+        object.on_dismiss_permission_prompt = Some(on_dismiss_permission_prompt::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl PermissionHandler for () {}
+
+#[derive(Debug)]
+struct PermissionRule {
+    origin: String,
+    permissions: PermissionRequestTypes,
+    media: MediaAccessPermissions,
+}
+
+/// A [PermissionHandler] granting permissions per origin and denying everything else.
+///
+/// Origins are matched against the requesting origin, like `https://example.com`, and may
+/// contain `*` wildcards matching any sequence of characters. The permissions of every matching
+/// rule are combined. A request is only allowed if every requested permission is allowed, so
+/// empty requests and permissions unknown to this crate are always denied.
+///
+/// ```no_run
+/// use cef::permission_handler::{
+///     MediaAccessPermissions, PermissionPolicy, PermissionRequestTypes,
+/// };
+///
+/// let policy = PermissionPolicy::new();
+/// policy
+///     .allow("https://meet.example.com", PermissionRequestTypes::NOTIFICATIONS)
+///     .allow_media(
+///         "https://meet.example.com",
+///         MediaAccessPermissions::DEVICE_AUDIO_CAPTURE | MediaAccessPermissions::DEVICE_VIDEO_CAPTURE,
+///     )
+///     .allow("https://*.example.com", PermissionRequestTypes::CLIPBOARD);
+/// ```
+#[derive(Debug, Default)]
+pub struct PermissionPolicy {
+    rules: Mutex<Vec<PermissionRule>>,
+}
+
+impl PermissionPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow `permissions` for the origins matching `origin`.
+    pub fn allow(&self, origin: &str, permissions: PermissionRequestTypes) -> &Self {
+        self.add_rule(origin, permissions, MediaAccessPermissions::empty())
+    }
+
+    /// Allow the media access `permissions` for the origins matching `origin`.
+    pub fn allow_media(&self, origin: &str, permissions: MediaAccessPermissions) -> &Self {
+        self.add_rule(origin, PermissionRequestTypes::empty(), permissions)
+    }
+
+    /// Remove every rule for the pattern `origin`.
+    pub fn revoke(&self, origin: &str) -> &Self {
+        self.rules
+            .lock()
+            .unwrap()
+            .retain(|rule| rule.origin != origin);
+        self
+    }
+
+    /// Returns the permissions allowed for `origin`.
+    pub fn allowed(&self, origin: &str) -> PermissionRequestTypes {
+        let origin = origin.trim_end_matches('/');
+        self.rules
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|rule| matches_pattern(&rule.origin, origin))
+            .fold(PermissionRequestTypes::empty(), |allowed, rule| {
+                allowed | rule.permissions
+            })
+    }
+
+    /// Returns the media access permissions allowed for `origin`.
+    pub fn allowed_media(&self, origin: &str) -> MediaAccessPermissions {
+        let origin = origin.trim_end_matches('/');
+        self.rules
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|rule| matches_pattern(&rule.origin, origin))
+            .fold(MediaAccessPermissions::empty(), |allowed, rule| {
+                allowed | rule.media
+            })
+    }
+
+    /// Returns `true` if every permission of `requested` is allowed for `origin`. Empty requests
+    /// and requests with permissions unknown to this crate are denied.
+    pub fn allows(&self, origin: &str, requested: PermissionRequestTypes) -> bool {
+        !requested.is_empty()
+            && PermissionRequestTypes::from_bits(requested.bits()).is_some()
+            && self.allowed(origin).contains(requested)
+    }
+
+    /// Returns `true` if every media access permission of `requested` is allowed for `origin`.
+    /// Empty requests and requests with permissions unknown to this crate are denied.
+    pub fn allows_media(&self, origin: &str, requested: MediaAccessPermissions) -> bool {
+        !requested.is_empty()
+            && MediaAccessPermissions::from_bits(requested.bits()).is_some()
+            && self.allowed_media(origin).contains(requested)
+    }
+
+    fn add_rule(
+        &self,
+        origin: &str,
+        permissions: PermissionRequestTypes,
+        media: MediaAccessPermissions,
+    ) -> &Self {
+        self.rules.lock().unwrap().push(PermissionRule {
+            origin: origin.to_string(),
+            permissions,
+            media,
+        });
+        self
+    }
+}
+
+impl PermissionHandler for PermissionPolicy {
+    fn on_request_media_access_permission(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        requesting_origin: CefString,
+        requested_permissions: MediaAccessPermissions,
+        callback: MediaAccessCallback,
+    ) -> bool {
+        if self.allows_media(&requesting_origin.to_string(), requested_permissions) {
+            callback.cont(requested_permissions);
+        } else {
+            callback.cont(MediaAccessPermissions::empty());
+        }
+        true
+    }
+
+    fn on_show_permission_prompt(
+        &self,
+        _browser: Browser,
+        _prompt_id: u64,
+        requesting_origin: CefString,
+        requested_permissions: PermissionRequestTypes,
+        callback: PermissionPromptCallback,
+    ) -> bool {
+        if self.allows(&requesting_origin.to_string(), requested_permissions) {
+            callback.cont(PermissionRequestResult::CEF_PERMISSION_RESULT_ACCEPT);
+        } else {
+            callback.cont(PermissionRequestResult::CEF_PERMISSION_RESULT_DENY);
+        }
+        true
+    }
+}
+
+extern "C" fn on_request_media_access_permission<H: PermissionHandler>(
+    this: *mut cef_permission_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    requesting_origin: *const cef_string_t,
+    requested_permissions: u32,
+    callback: *mut cef_media_access_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let requesting_origin = unsafe { CefString::from_raw(requesting_origin).unwrap_or_default() };
+    let requested_permissions = MediaAccessPermissions::from_bits_retain(requested_permissions);
+    let callback = unsafe { MediaAccessCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_request_media_access_permission(
+            browser,
+            frame,
+            requesting_origin,
+            requested_permissions,
+            callback,
+        )
+        .into()
+}
+
+extern "C" fn on_show_permission_prompt<H: PermissionHandler>(
+    this: *mut cef_permission_handler_t,
+    browser: *mut cef_browser_t,
+    prompt_id: u64,
+    requesting_origin: *const cef_string_t,
+    requested_permissions: u32,
+    callback: *mut cef_permission_prompt_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let requesting_origin = unsafe { CefString::from_raw(requesting_origin).unwrap_or_default() };
+    let requested_permissions = PermissionRequestTypes::from_bits_retain(requested_permissions);
+    let callback = unsafe { PermissionPromptCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_show_permission_prompt(
+            browser,
+            prompt_id,
+            requesting_origin,
+            requested_permissions,
+            callback,
+        )
+        .into()
+}
+
+extern "C" fn on_dismiss_permission_prompt<H: PermissionHandler>(
+    this: *mut cef_permission_handler_t,
+    browser: *mut cef_browser_t,
+    prompt_id: u64,
+    result: cef_permission_request_result_t,
+) {
+    let handler: &mut RcImpl<_, &H> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler
+        .interface
+        .on_dismiss_permission_prompt(browser, prompt_id, result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PermissionPolicy {
+        let policy = PermissionPolicy::new();
+        policy
+            .allow(
+                "https://meet.example.com",
+                PermissionRequestTypes::NOTIFICATIONS | PermissionRequestTypes::CLIPBOARD,
+            )
+            .allow("https://*.example.com", PermissionRequestTypes::GEOLOCATION)
+            .allow_media(
+                "https://meet.example.com",
+                MediaAccessPermissions::DEVICE_AUDIO_CAPTURE,
+            );
+        policy
+    }
+
+    #[test]
+    fn allowed_combines_matching_rules() {
+        let policy = policy();
+
+        assert_eq!(
+            policy.allowed("https://meet.example.com/"),
+            PermissionRequestTypes::NOTIFICATIONS
+                | PermissionRequestTypes::CLIPBOARD
+                | PermissionRequestTypes::GEOLOCATION
+        );
+        assert_eq!(
+            policy.allowed("https://docs.example.com"),
+            PermissionRequestTypes::GEOLOCATION
+        );
+        assert_eq!(
+            policy.allowed("https://example.org"),
+            PermissionRequestTypes::empty()
+        );
+        assert_eq!(
+            policy.allowed_media("https://meet.example.com"),
+            MediaAccessPermissions::DEVICE_AUDIO_CAPTURE
+        );
+        assert_eq!(
+            policy.allowed_media("https://docs.example.com"),
+            MediaAccessPermissions::empty()
+        );
+    }
+
+    #[test]
+    fn revoke_removes_the_rules_of_a_pattern() {
+        let policy = policy();
+        policy.revoke("https://*.example.com");
+
+        assert_eq!(
+            policy.allowed("https://docs.example.com"),
+            PermissionRequestTypes::empty()
+        );
+        assert!(policy
+            .allowed("https://meet.example.com")
+            .contains(PermissionRequestTypes::NOTIFICATIONS));
+    }
+
+    #[test]
+    fn allows_requires_every_requested_permission() {
+        let policy = policy();
+
+        assert!(policy.allows(
+            "https://meet.example.com",
+            PermissionRequestTypes::NOTIFICATIONS | PermissionRequestTypes::GEOLOCATION
+        ));
+        assert!(!policy.allows(
+            "https://meet.example.com",
+            PermissionRequestTypes::NOTIFICATIONS | PermissionRequestTypes::MIDI_SYSEX
+        ));
+        assert!(policy.allows_media(
+            "https://meet.example.com",
+            MediaAccessPermissions::DEVICE_AUDIO_CAPTURE
+        ));
+        assert!(!policy.allows_media(
+            "https://meet.example.com",
+            MediaAccessPermissions::DEVICE_AUDIO_CAPTURE
+                | MediaAccessPermissions::DEVICE_VIDEO_CAPTURE
+        ));
+    }
+
+    #[test]
+    fn allows_denies_empty_and_unknown_requests() {
+        let policy = PermissionPolicy::new();
+        policy
+            .allow("*", PermissionRequestTypes::all())
+            .allow_media("*", MediaAccessPermissions::all());
+        let unknown = 1 << 31;
+
+        assert!(!policy.allows("https://example.com", PermissionRequestTypes::empty()));
+        assert!(!policy.allows(
+            "https://example.com",
+            PermissionRequestTypes::from_bits_retain(unknown)
+        ));
+        assert!(!policy.allows(
+            "https://example.com",
+            PermissionRequestTypes::from_bits_retain(
+                unknown | PermissionRequestTypes::NOTIFICATIONS.bits()
+            )
+        ));
+        assert!(!policy.allows_media("https://example.com", MediaAccessPermissions::empty()));
+        assert!(!policy.allows_media(
+            "https://example.com",
+            MediaAccessPermissions::from_bits_retain(unknown)
+        ));
+    }
+}
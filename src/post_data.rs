@@ -0,0 +1,181 @@
+use std::ptr::null_mut;
+
+use cef_sys::{
+    cef_post_data_create, cef_post_data_element_create, cef_post_data_element_t, cef_post_data_t,
+    cef_postdataelement_type_t,
+};
+
+use crate::{
+    string::{CefString, CefStringError},
+    wrapper,
+};
+
+wrapper!(
+    #[doc = "See [cef_post_data_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct PostData(cef_post_data_t);
+    pub fn remove_elements(&self);
+);
+
+impl Default for PostData {
+    fn default() -> Self {
+        unsafe { PostData::from_raw(cef_post_data_create()) }
+    }
+}
+
+impl PostData {
+    /// Create post data containing a single bytes element.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let post_data = Self::default();
+        post_data.add_element(PostDataElement::from_bytes(bytes));
+        post_data
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    /// Returns `true` if the underlying post data includes elements that are not represented by
+    /// this object, like multi-part file upload data.
+    pub fn has_excluded_elements(&self) -> bool {
+        self.0
+            .has_excluded_elements
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_element_count(&self) -> usize {
+        self.0
+            .get_element_count
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_elements(&self) -> Vec<PostDataElement> {
+        let Some(f) = self.0.get_elements else {
+            return Vec::new();
+        };
+
+        let mut count = self.get_element_count();
+        let mut elements = vec![null_mut(); count];
+        unsafe { f(self.0.get_raw(), &mut count, elements.as_mut_ptr()) };
+        elements.truncate(count);
+
+        elements
+            .into_iter()
+            .filter(|e| !e.is_null())
+            .map(|e| unsafe { PostDataElement::from_raw(e) })
+            .collect()
+    }
+
+    /// Returns `true` if the element was removed.
+    pub fn remove_element(&self, element: &PostDataElement) -> bool {
+        self.0
+            .remove_element
+            .map(|f| unsafe { f(self.0.get_raw(), element.clone().into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the element was added.
+    pub fn add_element(&self, element: PostDataElement) -> bool {
+        self.0
+            .add_element
+            .map(|f| unsafe { f(self.0.get_raw(), element.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Concatenate the bytes of all bytes elements. File and empty elements are skipped.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.get_elements()
+            .iter()
+            .flat_map(|e| e.get_bytes())
+            .collect()
+    }
+}
+
+/// Type of a [PostDataElement]. See [cef_postdataelement_type_t] for more documentation.
+pub type PostDataElementType = cef_postdataelement_type_t;
+
+wrapper!(
+    #[doc = "See [cef_post_data_element_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct PostDataElement(cef_post_data_element_t);
+    pub fn set_to_empty(&self);
+);
+
+impl Default for PostDataElement {
+    fn default() -> Self {
+        unsafe { PostDataElement::from_raw(cef_post_data_element_create()) }
+    }
+}
+
+impl PostDataElement {
+    /// Create an element holding a copy of `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let element = Self::default();
+        element.set_to_bytes(bytes);
+        element
+    }
+
+    /// Create an element whose contents are read from `file_name` when the request is sent.
+    pub fn from_file(file_name: CefString) -> Self {
+        let element = Self::default();
+        element.set_to_file(file_name);
+        element
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    pub fn set_to_file(&self, file_name: CefString) {
+        if let Some(f) = self.0.set_to_file {
+            unsafe { f(self.0.get_raw(), &file_name.get_raw()) };
+        }
+    }
+
+    /// The bytes are copied.
+    pub fn set_to_bytes(&self, bytes: &[u8]) {
+        if let Some(f) = self.0.set_to_bytes {
+            unsafe { f(self.0.get_raw(), bytes.len(), bytes.as_ptr() as *const _) };
+        }
+    }
+
+    pub fn get_type(&self) -> PostDataElementType {
+        self.0
+            .get_type
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(PostDataElementType::PDE_TYPE_EMPTY)
+    }
+
+    pub fn get_file(&self) -> Result<CefString, CefStringError> {
+        let get_file = self.0.get_file.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_file(self.0.get_raw())) }
+    }
+
+    pub fn get_bytes_count(&self) -> usize {
+        self.0
+            .get_bytes_count
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    /// Returns a copy of the bytes. Empty for file and empty elements.
+    pub fn get_bytes(&self) -> Vec<u8> {
+        let Some(f) = self.0.get_bytes else {
+            return Vec::new();
+        };
+
+        let mut bytes = vec![0u8; self.get_bytes_count()];
+        let read = unsafe { f(self.0.get_raw(), bytes.len(), bytes.as_mut_ptr() as *mut _) };
+        bytes.truncate(read);
+        bytes
+    }
+}
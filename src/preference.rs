@@ -0,0 +1,115 @@
+use std::ptr::null_mut;
+
+use cef_sys::{
+    cef_preference_manager_get_global, cef_preference_manager_t, cef_preference_registrar_t,
+    cef_string_t, cef_string_utf16_clear,
+};
+
+use crate::{
+    string::CefString,
+    values::{DictionaryValue, Value},
+    wrapper,
+};
+
+wrapper!(
+    #[doc = "See [cef_preference_manager_t] for more documentation."]
+    #[doc = ""]
+    #[doc = "Preference names are dot separated paths like `download.default_directory`. The"]
+    #[doc = "methods must be called on the browser process UI thread."]
+    #[derive(Debug, Clone)]
+    pub struct PreferenceManager(cef_preference_manager_t);
+);
+
+impl PreferenceManager {
+    /// Returns the global preference manager. Only valid after the context has been initialized.
+    pub fn global() -> Option<Self> {
+        let manager = unsafe { cef_preference_manager_get_global() };
+
+        (!manager.is_null()).then(|| unsafe { PreferenceManager::from_raw(manager) })
+    }
+
+    /// Returns `true` if a preference with the specified `name` exists.
+    pub fn has_preference(&self, name: CefString) -> bool {
+        self.0
+            .has_preference
+            .map(|f| unsafe { f(self.0.get_raw(), &name.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns a copy of the value for the preference with the specified `name`, or `None` if the
+    /// preference doesn't exist.
+    pub fn get_preference(&self, name: CefString) -> Option<Value> {
+        let value = self
+            .0
+            .get_preference
+            .map(|f| unsafe { f(self.0.get_raw(), &name.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { Value::from_raw(value) })
+    }
+
+    /// Returns all preferences as a dictionary. If `include_defaults` is `true` preferences
+    /// currently at their default value are included as well.
+    pub fn get_all_preferences(&self, include_defaults: bool) -> Option<DictionaryValue> {
+        let preferences = self
+            .0
+            .get_all_preferences
+            .map(|f| unsafe { f(self.0.get_raw(), include_defaults.into()) })?;
+
+        (!preferences.is_null()).then(|| unsafe { DictionaryValue::from_raw(preferences) })
+    }
+
+    /// Returns `true` if the preference with the specified `name` can be modified with
+    /// [PreferenceManager::set_preference].
+    pub fn can_set_preference(&self, name: CefString) -> bool {
+        self.0
+            .can_set_preference
+            .map(|f| unsafe { f(self.0.get_raw(), &name.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Set the preference with the specified `name` to `value`, or restore its default value if
+    /// `value` is `None`. Returns the error reported by cef if the preference can't be set.
+    pub fn set_preference(&self, name: CefString, value: Option<Value>) -> Result<(), CefString> {
+        let set_preference = self.0.set_preference.unwrap();
+        let value = value.map(|v| unsafe { v.into_raw() }).unwrap_or(null_mut());
+        let mut error: cef_string_t = unsafe { std::mem::zeroed() };
+
+        let res = unsafe { set_preference(self.0.get_raw(), &name.get_raw(), value, &mut error) };
+        let message = unsafe { CefString::from_raw(&error) }
+            .ok()
+            .filter(|m| !m.to_string().is_empty());
+        unsafe { cef_string_utf16_clear(&mut error) };
+
+        if res > 0 {
+            Ok(())
+        } else {
+            Err(message.unwrap_or_else(|| CefString::new("Failed to set preference")))
+        }
+    }
+}
+
+/// See [cef_preference_registrar_t] for more documentation.
+///
+/// Only valid during [BrowserProcessHandler::on_register_custom_preferences].
+///
+/// [BrowserProcessHandler::on_register_custom_preferences]: crate::browser_process_handler::BrowserProcessHandler::on_register_custom_preferences
+#[derive(Debug)]
+pub struct PreferenceRegistrar(*mut cef_preference_registrar_t);
+
+impl PreferenceRegistrar {
+    pub(crate) fn from_mut_ptr(raw: *mut cef_preference_registrar_t) -> Self {
+        Self(raw)
+    }
+
+    /// Register a preference with the specified `name` and `default_value`. The type of the
+    /// default value is the type the preference keeps. Returns `false` if the preference is
+    /// already registered or the name is invalid.
+    pub fn add_preference(&mut self, name: CefString, default_value: Value) -> bool {
+        unsafe {
+            (*self.0)
+                .add_preference
+                .map(|f| f(self.0, &name.get_raw(), default_value.into_raw()) > 0)
+                .unwrap_or(false)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Proxy configuration module
+//!
+//! [ProxyConfig] describes the `proxy` preference of a
+//! [RequestContext](crate::request_context::RequestContext) and is applied with
+//! [RequestContext::set_proxy](crate::request_context::RequestContext::set_proxy):
+//!
+//! ```no_run
+//! use cef::{proxy::ProxyConfig, request_context::RequestContext};
+//!
+//! let context = RequestContext::global().expect("cef is initialized");
+//! let config = ProxyConfig::FixedServers {
+//!     servers: "http=proxy.internal:3128;https=proxy.internal:3128".to_string(),
+//!     bypass_list: vec!["localhost".to_string(), "*.internal".to_string()],
+//! };
+//! if let Err(error) = context.set_proxy(&config) {
+//!     eprintln!("Failed to set the proxy: {error}");
+//! }
+//! ```
+
+use crate::{
+    string::CefString,
+    values::{DictionaryValue, Value},
+    ValueType,
+};
+
+/// The proxy settings used by a request context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProxyConfig {
+    /// Never use a proxy.
+    Direct,
+    /// Detect the proxy configuration with WPAD.
+    AutoDetect,
+    /// Use the PAC script at the given URL.
+    PacUrl(String),
+    /// Use fixed proxy servers.
+    FixedServers {
+        /// The proxy rules, like `proxy.example.com:8080` or
+        /// `http=proxy1:3128;https=proxy2:3128;socks=socks5://proxy3:1080`.
+        servers: String,
+        /// Hosts that are reached without proxy, like `localhost`, `*.example.com` or
+        /// `192.168.0.0/16`.
+        bypass_list: Vec<String>,
+    },
+    /// Use the proxy settings of the operating system.
+    System,
+}
+
+impl ProxyConfig {
+    /// The value of the `mode` key of the preference dictionary.
+    pub fn mode(&self) -> &'static str {
+        match self {
+            ProxyConfig::Direct => "direct",
+            ProxyConfig::AutoDetect => "auto_detect",
+            ProxyConfig::PacUrl(_) => "pac_script",
+            ProxyConfig::FixedServers { .. } => "fixed_servers",
+            ProxyConfig::System => "system",
+        }
+    }
+
+    /// Build the dictionary stored in the `proxy` preference.
+    pub fn to_value(&self) -> Value {
+        let dictionary = DictionaryValue::default();
+        dictionary.set_value("mode".into(), self.mode().into());
+
+        match self {
+            ProxyConfig::PacUrl(url) => {
+                dictionary.set_value("pac_url".into(), url.as_str().into());
+            }
+            ProxyConfig::FixedServers {
+                servers,
+                bypass_list,
+            } => {
+                dictionary.set_value("server".into(), servers.as_str().into());
+                if !bypass_list.is_empty() {
+                    dictionary
+                        .set_value("bypass_list".into(), bypass_list.join(",").as_str().into());
+                }
+            }
+            _ => {}
+        }
+
+        dictionary.into()
+    }
+
+    /// Read the config from the `proxy` preference. Returns `None` if the value is not a valid
+    /// proxy dictionary.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let dictionary = value.get_dictionary()?;
+        let string = |key: &str| {
+            dictionary
+                .get_value(CefString::from(key))
+                .filter(|v| v.get_type() == ValueType::VTYPE_STRING)
+                .and_then(|v| v.get_string().ok())
+                .map(|v| v.to_string())
+        };
+
+        let config = match string("mode")?.as_str() {
+            "direct" => ProxyConfig::Direct,
+            "auto_detect" => ProxyConfig::AutoDetect,
+            "pac_script" => ProxyConfig::PacUrl(string("pac_url")?),
+            "fixed_servers" => ProxyConfig::FixedServers {
+                servers: string("server")?,
+                bypass_list: string("bypass_list")
+                    .map(|list| {
+                        list.split([',', ';'])
+                            .map(str::trim)
+                            .filter(|host| !host.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            },
+            "system" => ProxyConfig::System,
+            _ => return None,
+        };
+        Some(config)
+    }
+}
@@ -1,4 +1,4 @@
-use cef_sys::{_cef_accelerated_paint_info_t, _cef_accelerated_paint_native_pixmap_plane_info_t, cef_point_t, cef_range_t, cef_rect_t, cef_screen_info_t};
+use cef_sys::{_cef_accelerated_paint_info_t, _cef_accelerated_paint_native_pixmap_plane_info_t, cef_point_t, cef_range_t, cef_rect_t, cef_screen_info_t, cef_size_t};
 
 pub type ColorType = cef_sys::cef_color_type_t;
 pub type PaintElementType = cef_sys::cef_paint_element_type_t;
@@ -77,6 +77,41 @@ impl CefPoint {
     }
 }
 
+impl From<cef_point_t> for CefPoint {
+    fn from(raw: cef_point_t) -> Self {
+        CefPoint { x: raw.x, y: raw.y }
+    }
+}
+
+/// See [cef_size_t] for more documentation.
+#[derive(Debug, Clone, Default)]
+pub struct CefSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl CefSize {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_raw(self) -> cef_size_t {
+        cef_size_t {
+            width: self.width as i32,
+            height: self.height as i32,
+        }
+    }
+}
+
+impl From<cef_size_t> for CefSize {
+    fn from(raw: cef_size_t) -> Self {
+        CefSize {
+            width: raw.width as u32,
+            height: raw.height as u32,
+        }
+    }
+}
+
 /// See [cef_range_t] for more documentation.
 #[derive(Debug, Clone, Default)]
 pub struct CefRange {
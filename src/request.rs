@@ -0,0 +1,275 @@
+use std::ptr::null_mut;
+
+use bitflags::bitflags;
+use cef_sys::{cef_request_create, cef_request_t, cef_urlrequest_flags_t};
+
+use crate::{
+    post_data::PostData,
+    string::{CefString, CefStringError, CefStringMultimap},
+    wrapper, ReferrerPolicy, ResourceType, TransitionType,
+};
+
+bitflags! {
+    /// Flags used to customize the behavior of a url request. See [cef_urlrequest_flags_t] for
+    /// more documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RequestFlags: i32 {
+        /// Skip the cache, like a `Cache-Control: no-cache` request header.
+        const SKIP_CACHE = cef_urlrequest_flags_t::UR_FLAG_SKIP_CACHE.0 as i32;
+        /// Only use the cache. The request fails if the response isn't cached.
+        const ONLY_FROM_CACHE = cef_urlrequest_flags_t::UR_FLAG_ONLY_FROM_CACHE.0 as i32;
+        /// Don't read from or write to the cache.
+        const DISABLE_CACHE = cef_urlrequest_flags_t::UR_FLAG_DISABLE_CACHE.0 as i32;
+        /// Send cookies and save cookies from the response.
+        const ALLOW_STORED_CREDENTIALS = cef_urlrequest_flags_t::UR_FLAG_ALLOW_STORED_CREDENTIALS.0 as i32;
+        /// Report upload progress events.
+        const REPORT_UPLOAD_PROGRESS = cef_urlrequest_flags_t::UR_FLAG_REPORT_UPLOAD_PROGRESS.0 as i32;
+        /// Don't report the response body to the client.
+        const NO_DOWNLOAD_DATA = cef_urlrequest_flags_t::UR_FLAG_NO_DOWNLOAD_DATA.0 as i32;
+        /// Don't retry a request that failed with a 5XX response.
+        const NO_RETRY_ON_5XX = cef_urlrequest_flags_t::UR_FLAG_NO_RETRY_ON_5XX.0 as i32;
+        /// Stop the request at a redirect instead of following it.
+        const STOP_ON_REDIRECT = cef_urlrequest_flags_t::UR_FLAG_STOP_ON_REDIRECT.0 as i32;
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_request_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct Request(cef_request_t);
+);
+
+impl Default for Request {
+    fn default() -> Self {
+        unsafe { Request::from_raw(cef_request_create()) }
+    }
+}
+
+impl Request {
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    pub fn get_url(&self) -> Result<CefString, CefStringError> {
+        let get_url = self.0.get_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_url(self.0.get_raw())) }
+    }
+
+    pub fn set_url(&self, url: CefString) {
+        if let Some(f) = self.0.set_url {
+            unsafe { f(self.0.get_raw(), &url.get_raw()) };
+        }
+    }
+
+    pub fn get_method(&self) -> Result<CefString, CefStringError> {
+        let get_method = self.0.get_method.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_method(self.0.get_raw())) }
+    }
+
+    pub fn set_method(&self, method: CefString) {
+        if let Some(f) = self.0.set_method {
+            unsafe { f(self.0.get_raw(), &method.get_raw()) };
+        }
+    }
+
+    /// Set the referrer URL and policy. An empty `referrer_url` clears the referrer.
+    pub fn set_referrer(&self, referrer_url: CefString, policy: ReferrerPolicy) {
+        if let Some(f) = self.0.set_referrer {
+            unsafe { f(self.0.get_raw(), &referrer_url.get_raw(), policy) };
+        }
+    }
+
+    pub fn get_referrer_url(&self) -> Result<CefString, CefStringError> {
+        let get_referrer_url = self.0.get_referrer_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_referrer_url(self.0.get_raw())) }
+    }
+
+    pub fn get_referrer_policy(&self) -> ReferrerPolicy {
+        self.0
+            .get_referrer_policy
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ReferrerPolicy::REFERRER_POLICY_DEFAULT)
+    }
+
+    pub fn get_post_data(&self) -> Option<PostData> {
+        let post_data = self
+            .0
+            .get_post_data
+            .map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!post_data.is_null()).then(|| unsafe { PostData::from_raw(post_data) })
+    }
+
+    pub fn set_post_data(&self, post_data: Option<PostData>) {
+        if let Some(f) = self.0.set_post_data {
+            let post_data = post_data
+                .map(|p| unsafe { p.into_raw() })
+                .unwrap_or(null_mut());
+            unsafe { f(self.0.get_raw(), post_data) };
+        }
+    }
+
+    /// Returns all headers. A header name can appear multiple times.
+    pub fn get_header_map(&self) -> CefStringMultimap {
+        let mut headers = CefStringMultimap::default();
+        if let Some(f) = self.0.get_header_map {
+            unsafe { f(self.0.get_raw(), headers.get_raw_mut()) };
+        }
+        headers
+    }
+
+    /// Replace all headers. The `Referer` header is ignored, use [Request::set_referrer] instead.
+    pub fn set_header_map(&self, mut headers: CefStringMultimap) {
+        if let Some(f) = self.0.set_header_map {
+            unsafe { f(self.0.get_raw(), headers.get_raw_mut()) };
+        }
+    }
+
+    /// Returns the first header value for `name` or an error if the header is not found.
+    pub fn get_header_by_name(&self, name: CefString) -> Result<CefString, CefStringError> {
+        let get_header_by_name = self.0.get_header_by_name.unwrap();
+
+        unsafe {
+            CefString::from_userfree_cef(get_header_by_name(self.0.get_raw(), &name.get_raw()))
+        }
+    }
+
+    /// Set the header `name` to `value`. If `overwrite` is `true` any existing values will be
+    /// replaced with the new value. If `overwrite` is `false` any existing values will not be
+    /// overwritten.
+    pub fn set_header_by_name(&self, name: CefString, value: CefString, overwrite: bool) {
+        if let Some(f) = self.0.set_header_by_name {
+            unsafe {
+                f(
+                    self.0.get_raw(),
+                    &name.get_raw(),
+                    &value.get_raw(),
+                    overwrite.into(),
+                )
+            };
+        }
+    }
+
+    pub fn get_flags(&self) -> RequestFlags {
+        self.0
+            .get_flags
+            .map(|f| RequestFlags::from_bits_truncate(unsafe { f(self.0.get_raw()) }))
+            .unwrap_or_default()
+    }
+
+    pub fn set_flags(&self, flags: RequestFlags) {
+        if let Some(f) = self.0.set_flags {
+            unsafe { f(self.0.get_raw(), flags.bits()) };
+        }
+    }
+
+    /// Returns the URL to the first party for cookies used in combination with url requests.
+    pub fn get_first_party_for_cookies(&self) -> Result<CefString, CefStringError> {
+        let get_first_party_for_cookies = self.0.get_first_party_for_cookies.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_first_party_for_cookies(self.0.get_raw())) }
+    }
+
+    pub fn set_first_party_for_cookies(&self, url: CefString) {
+        if let Some(f) = self.0.set_first_party_for_cookies {
+            unsafe { f(self.0.get_raw(), &url.get_raw()) };
+        }
+    }
+
+    /// Only available in the browser process.
+    pub fn get_resource_type(&self) -> ResourceType {
+        self.0
+            .get_resource_type
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ResourceType::RT_MAIN_FRAME)
+    }
+
+    /// Only available in the browser process and only for requests originating from a frame.
+    pub fn get_transition_type(&self) -> TransitionType {
+        self.0
+            .get_transition_type
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(TransitionType::TT_LINK)
+    }
+
+    /// Returns the globally unique identifier for this request or 0 if not specified.
+    pub fn get_identifier(&self) -> u64 {
+        self.0
+            .get_identifier
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&Request> for http::Request<Vec<u8>> {
+    type Error = http::Error;
+
+    /// The body is built from the bytes elements of the post data.
+    fn try_from(request: &Request) -> Result<Self, Self::Error> {
+        let mut builder = http::Request::builder()
+            .uri(request.get_url().unwrap_or_default().to_string())
+            .method(
+                request
+                    .get_method()
+                    .unwrap_or_default()
+                    .to_string()
+                    .as_str(),
+            );
+
+        for (name, value) in request.get_header_map().to_vec() {
+            builder = builder.header(name.to_string(), value.to_string());
+        }
+        if let Ok(referrer) = request.get_referrer_url() {
+            if !referrer.0.is_empty() {
+                builder = builder.header(http::header::REFERER, referrer.to_string());
+            }
+        }
+
+        let body = request
+            .get_post_data()
+            .map(|p| p.to_bytes())
+            .unwrap_or_default();
+        builder.body(body)
+    }
+}
+
+#[cfg(feature = "http")]
+impl<B: AsRef<[u8]>> From<&http::Request<B>> for Request {
+    /// Header values that are not valid UTF-8 are skipped. An empty body produces no post data.
+    fn from(request: &http::Request<B>) -> Self {
+        let res = Request::default();
+        res.set_url(CefString::from(request.uri().to_string().as_str()));
+        res.set_method(CefString::from(request.method().as_str()));
+
+        let headers = request
+            .headers()
+            .iter()
+            .filter(|(name, _)| **name != http::header::REFERER)
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+            .collect();
+        res.set_header_map(headers);
+        if let Some(referrer) = request
+            .headers()
+            .get(http::header::REFERER)
+            .and_then(|r| r.to_str().ok())
+        {
+            res.set_referrer(
+                CefString::from(referrer),
+                ReferrerPolicy::REFERRER_POLICY_DEFAULT,
+            );
+        }
+
+        let body = request.body().as_ref();
+        if !body.is_empty() {
+            res.set_post_data(Some(PostData::from_bytes(body)));
+        }
+        res
+    }
+}
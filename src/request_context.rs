@@ -0,0 +1,318 @@
+use std::{
+    ffi::c_int,
+    ptr::{null, null_mut},
+};
+
+use cef_sys::{
+    cef_browser_t, cef_create_context_shared, cef_frame_t, cef_request_context_create_context,
+    cef_request_context_get_global_context, cef_request_context_handler_t,
+    cef_request_context_settings_t, cef_request_context_t, cef_request_t,
+    cef_resource_request_handler_t, cef_string_t,
+};
+
+use crate::{
+    cookie::CookieManager,
+    frame::Frame,
+    preference::PreferenceManager,
+    proxy::ProxyConfig,
+    rc::RcImpl,
+    request::Request,
+    resource_request_handler::{browser_and_frame, ResourceRequestHandler},
+    scheme::SchemeHandlerFactory,
+    string::{CefString, CefStringError},
+    values::{DictionaryValue, Value},
+    wrapper, Browser,
+};
+
+/// See [cef_request_context_settings_t] for more documentation.
+#[derive(Debug, Default, Clone)]
+pub struct RequestContextSettings {
+    /// The directory where cache data is stored on disk. It must be equal to or a child of
+    /// [Settings::root_cache_path](crate::Settings::root_cache_path). An empty value
+    /// uses an in-memory cache and stores no data on disk, except for some plugin data.
+    pub cache_path: CefString,
+    /// Persist session cookies, the cookies without an expiry date. Requires a `cache_path`.
+    pub persist_session_cookies: bool,
+    /// Persist user preferences as a JSON file in the cache path. Requires a `cache_path`.
+    pub persist_user_preferences: bool,
+    /// Comma delimited ordered list of language codes without any whitespace that will be used
+    /// in the `Accept-Language` HTTP header. Inherited from the global settings if empty.
+    pub accept_language_list: CefString,
+    /// Comma delimited list of schemes supporting cookies in addition to the default ones.
+    pub cookieable_schemes_list: CefString,
+    /// Disable cookies for the default schemes, `http`, `https`, `ws` and `wss`.
+    pub cookieable_schemes_exclude_defaults: bool,
+}
+
+impl RequestContextSettings {
+    pub fn get_raw(&self) -> cef_request_context_settings_t {
+        cef_request_context_settings_t {
+            size: std::mem::size_of::<cef_request_context_settings_t>(),
+            cache_path: self.cache_path.get_raw(),
+            persist_session_cookies: self.persist_session_cookies as c_int,
+            persist_user_preferences: self.persist_user_preferences as c_int,
+            accept_language_list: self.accept_language_list.get_raw(),
+            cookieable_schemes_list: self.cookieable_schemes_list.get_raw(),
+            cookieable_schemes_exclude_defaults: self.cookieable_schemes_exclude_defaults as c_int,
+        }
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_request_context_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct RequestContext(cef_request_context_t);
+);
+
+impl RequestContext {
+    /// Returns the global context object, or `None` if cef is not initialized yet.
+    pub fn global() -> Option<Self> {
+        let context = unsafe { cef_request_context_get_global_context() };
+
+        (!context.is_null()).then(|| unsafe { RequestContext::from_raw(context) })
+    }
+
+    /// Create a new context. Contexts with the same non-empty `cache_path` share storage.
+    ///
+    /// See [cef_request_context_create_context] for more documentation.
+    pub fn new<H: RequestContextHandler>(
+        settings: &RequestContextSettings,
+        handler: Option<H>,
+    ) -> Self {
+        let handler = handler.map(|h| h.into_raw()).unwrap_or(null_mut());
+
+        unsafe {
+            RequestContext::from_raw(cef_request_context_create_context(
+                &settings.get_raw(),
+                handler,
+            ))
+        }
+    }
+
+    /// Create a new context that shares storage with `other` and uses an optional `handler`.
+    ///
+    /// See [cef_create_context_shared] for more documentation.
+    pub fn shared<H: RequestContextHandler>(other: &RequestContext, handler: Option<H>) -> Self {
+        let handler = handler.map(|h| h.into_raw()).unwrap_or(null_mut());
+
+        unsafe {
+            RequestContext::from_raw(cef_create_context_shared(other.clone().into_raw(), handler))
+        }
+    }
+
+    /// Returns `true` if this object is pointing to the same context as `other`.
+    pub fn is_same(&self, other: &RequestContext) -> bool {
+        self.0
+            .is_same
+            .map(|f| unsafe { f(self.0.get_raw(), other.clone().into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if this object is sharing the same storage as `other`.
+    pub fn is_sharing_with(&self, other: &RequestContext) -> bool {
+        self.0
+            .is_sharing_with
+            .map(|f| unsafe { f(self.0.get_raw(), other.clone().into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if this object is the global context.
+    pub fn is_global(&self) -> bool {
+        self.0
+            .is_global
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the cache path for this object. It is empty for an in-memory cache.
+    pub fn get_cache_path(&self) -> Result<CefString, CefStringError> {
+        let get_cache_path = self.0.get_cache_path.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_cache_path(self.0.get_raw())) }
+    }
+
+    /// Returns the cookie manager for this context. Cookies are stored in the cache path of the
+    /// context if specified or in memory otherwise.
+    pub fn get_cookie_manager(&self) -> Option<CookieManager> {
+        let manager = self
+            .0
+            .get_cookie_manager
+            .map(|f| unsafe { f(self.0.get_raw(), null_mut()) })?;
+
+        (!manager.is_null()).then(|| unsafe { CookieManager::from_raw(manager) })
+    }
+
+    /// Register a scheme handler factory for this context only. See
+    /// [register_scheme_handler_factory](crate::scheme::register_scheme_handler_factory) for more
+    /// documentation.
+    pub fn register_scheme_handler_factory<F: SchemeHandlerFactory>(
+        &self,
+        scheme_name: CefString,
+        domain_name: Option<CefString>,
+        factory: F,
+    ) -> bool {
+        let domain_name = domain_name.as_ref().map(|d| d.get_raw());
+
+        self.0
+            .register_scheme_handler_factory
+            .map(|f| unsafe {
+                f(
+                    self.0.get_raw(),
+                    &scheme_name.get_raw(),
+                    domain_name
+                        .as_ref()
+                        .map(|d| d as *const _)
+                        .unwrap_or(null()),
+                    factory.into_raw(),
+                )
+            } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns the preference manager of this context.
+    pub fn get_preference_manager(&self) -> PreferenceManager {
+        unsafe { PreferenceManager(self.0.convert()) }
+    }
+
+    /// See [PreferenceManager::has_preference].
+    pub fn has_preference(&self, name: CefString) -> bool {
+        self.get_preference_manager().has_preference(name)
+    }
+
+    /// See [PreferenceManager::get_preference].
+    pub fn get_preference(&self, name: CefString) -> Option<Value> {
+        self.get_preference_manager().get_preference(name)
+    }
+
+    /// See [PreferenceManager::get_all_preferences].
+    pub fn get_all_preferences(&self, include_defaults: bool) -> Option<DictionaryValue> {
+        self.get_preference_manager()
+            .get_all_preferences(include_defaults)
+    }
+
+    /// See [PreferenceManager::can_set_preference].
+    pub fn can_set_preference(&self, name: CefString) -> bool {
+        self.get_preference_manager().can_set_preference(name)
+    }
+
+    /// See [PreferenceManager::set_preference].
+    pub fn set_preference(&self, name: CefString, value: Option<Value>) -> Result<(), CefString> {
+        self.get_preference_manager().set_preference(name, value)
+    }
+
+    /// Apply `config` to the `proxy` preference of this context. Returns an error if the
+    /// preference is managed by a policy or command line switch, or if cef rejects the value.
+    ///
+    /// This must be called on the browser process UI thread.
+    pub fn set_proxy(&self, config: &ProxyConfig) -> Result<(), CefString> {
+        let name = CefString::new("proxy");
+        if !self.can_set_preference(name.clone()) {
+            return Err(CefString::new("The proxy preference can't be modified"));
+        }
+
+        self.set_preference(name, Some(config.to_value()))
+    }
+
+    /// Returns the current proxy configuration of this context, or `None` if it is not set or
+    /// not valid.
+    pub fn get_proxy(&self) -> Option<ProxyConfig> {
+        let value = self.get_preference(CefString::new("proxy"))?;
+
+        ProxyConfig::from_value(&value)
+    }
+
+    /// Clear all scheme handler factories registered for this context.
+    pub fn clear_scheme_handler_factories(&self) -> bool {
+        self.0
+            .clear_scheme_handler_factories
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+}
+
+/// See [cef_request_context_handler_t] for more documentation.
+///
+/// The handler is kept alive by the [RequestContext] it is passed to.
+pub trait RequestContextHandler: Sized {
+    type ResourceRequestHandler: ResourceRequestHandler;
+
+    /// Called on the UI thread once the context has been initialized.
+    fn on_request_context_initialized(&self, _request_context: RequestContext) {}
+
+    /// Called on the IO thread before a resource request of this context is initiated, unless the
+    /// [RequestHandler](crate::request_handler::RequestHandler) of the browser already returned a
+    /// handler. `browser` and `frame` are `None` for requests that did not originate from a
+    /// browser window. Set `disable_default_handling` to `true` to disable default handling of
+    /// the request. Return `None` to load the request normally.
+    #[allow(clippy::too_many_arguments)]
+    fn get_resource_request_handler(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _is_navigation: bool,
+        _is_download: bool,
+        _request_initiator: CefString,
+        _disable_default_handling: &mut bool,
+    ) -> Option<&Self::ResourceRequestHandler> {
+        None
+    }
+
+    fn into_raw(self) -> *mut cef_request_context_handler_t {
+        let mut object: cef_request_context_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_request_context_initialized = Some(on_request_context_initialized::<Self>);
+        object.get_resource_request_handler = Some(get_resource_request_handler::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl RequestContextHandler for () {
+    type ResourceRequestHandler = ();
+}
+
+extern "C" fn on_request_context_initialized<H: RequestContextHandler>(
+    this: *mut cef_request_context_handler_t,
+    request_context: *mut cef_request_context_t,
+) {
+    let handler: &mut RcImpl<_, H> = RcImpl::get(this);
+    let request_context = unsafe { RequestContext::from_raw(request_context) };
+
+    handler
+        .interface
+        .on_request_context_initialized(request_context);
+}
+
+extern "C" fn get_resource_request_handler<H: RequestContextHandler>(
+    this: *mut cef_request_context_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    is_navigation: ::std::os::raw::c_int,
+    is_download: ::std::os::raw::c_int,
+    request_initiator: *const cef_string_t,
+    disable_default_handling: *mut ::std::os::raw::c_int,
+) -> *mut cef_resource_request_handler_t {
+    let handler: &mut RcImpl<_, H> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let request_initiator = unsafe { CefString::from_raw(request_initiator).unwrap_or_default() };
+    let mut disable = unsafe { *disable_default_handling } > 0;
+
+    let res = handler.interface.get_resource_request_handler(
+        browser,
+        frame,
+        request,
+        is_navigation > 0,
+        is_download > 0,
+        request_initiator,
+        &mut disable,
+    );
+    unsafe { *disable_default_handling = disable.into() };
+
+    match res {
+        Some(resource_request_handler) => resource_request_handler.get_raw(),
+        None => null_mut(),
+    }
+}
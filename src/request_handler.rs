@@ -0,0 +1,380 @@
+use std::ptr::null_mut;
+
+use cef_sys::{
+    cef_auth_callback_t, cef_browser_t, cef_callback_t, cef_errorcode_t, cef_frame_t,
+    cef_request_handler_t, cef_request_t, cef_resource_request_handler_t,
+    cef_select_client_certificate_callback_t, cef_sslinfo_t, cef_string_t,
+    cef_termination_status_t, cef_window_open_disposition_t, cef_x509certificate_t,
+};
+
+use crate::{
+    callback::Callback,
+    frame::Frame,
+    rc::RcImpl,
+    request::Request,
+    resource_request_handler::ResourceRequestHandler,
+    ssl_info::{SslInfo, X509Certificate},
+    string::CefString,
+    wrapper, Browser, ErrorCode, TerminationStatus, WindowOpenDisposition,
+};
+
+wrapper!(
+    #[doc = "See [cef_auth_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct AuthCallback(cef_auth_callback_t);
+    pub fn cancel(&self);
+);
+
+impl AuthCallback {
+    /// Continue the authentication request.
+    pub fn cont(&self, username: CefString, password: CefString) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), &username.get_raw(), &password.get_raw()) };
+        }
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_select_client_certificate_callback_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct SelectClientCertificateCallback(cef_select_client_certificate_callback_t);
+);
+
+impl SelectClientCertificateCallback {
+    /// Choose `cert` for client authentication or `None` to continue without a certificate.
+    pub fn select(&self, cert: Option<X509Certificate>) {
+        if let Some(f) = self.0.select {
+            let cert = cert.map(|c| unsafe { c.into_raw() }).unwrap_or(null_mut());
+            unsafe { f(self.0.get_raw(), cert) };
+        }
+    }
+}
+
+/// See [cef_request_handler_t] for more documentation.
+pub trait RequestHandler: Sized {
+    type ResourceRequestHandler: ResourceRequestHandler;
+
+    /// Called on the UI thread before browser navigation. Return `true` to cancel the navigation
+    /// or `false` to allow the navigation to proceed.
+    fn on_before_browse(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _request: Request,
+        _user_gesture: bool,
+        _is_redirect: bool,
+    ) -> bool {
+        false
+    }
+
+    /// Called on the UI thread before `open_url_from_tab` navigation, like middle-clicking a
+    /// link. Return `true` to cancel the navigation or `false` to allow the navigation to
+    /// proceed in the source browser's top-level frame.
+    fn on_open_urlfrom_tab(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _target_url: CefString,
+        _target_disposition: WindowOpenDisposition,
+        _user_gesture: bool,
+    ) -> bool {
+        false
+    }
+
+    /// Called on the IO thread before a resource request is initiated. Return a handler to
+    /// customize the request or `None` for default handling. Set `disable_default_handling` to
+    /// `true` to disable default handling of the request, in which case it will need to be
+    /// handled via [ResourceRequestHandler] or it will be canceled.
+    #[allow(clippy::too_many_arguments)]
+    fn get_resource_request_handler(
+        &self,
+        _browser: Browser,
+        _frame: Frame,
+        _request: Request,
+        _is_navigation: bool,
+        _is_download: bool,
+        _request_initiator: CefString,
+        _disable_default_handling: &mut bool,
+    ) -> Option<&Self::ResourceRequestHandler> {
+        None
+    }
+
+    /// Called on the IO thread when the browser needs credentials from the user. Return `true`
+    /// to continue the request and call [AuthCallback::cont] either in this method or at a later
+    /// time when the authentication information is available. Return `false` to cancel the
+    /// request immediately.
+    #[allow(clippy::too_many_arguments)]
+    fn get_auth_credentials(
+        &self,
+        _browser: Browser,
+        _origin_url: CefString,
+        _is_proxy: bool,
+        _host: CefString,
+        _port: u16,
+        _realm: Option<CefString>,
+        _scheme: Option<CefString>,
+        _callback: AuthCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called on the UI thread to handle requests for URLs with an invalid SSL certificate.
+    /// Return `true` and call [Callback::cont] either in this method or at a later time to
+    /// continue or cancel the request. Return `false` to cancel the request immediately.
+    fn on_certificate_error(
+        &self,
+        _browser: Browser,
+        _cert_error: ErrorCode,
+        _request_url: CefString,
+        _ssl_info: SslInfo,
+        _callback: Callback,
+    ) -> bool {
+        false
+    }
+
+    /// Called on the UI thread when a client certificate is being requested for authentication.
+    /// Return `false` to use the default behavior of automatically selecting the first
+    /// certificate. Return `true` and call [SelectClientCertificateCallback::select] either in
+    /// this method or at a later time to select a certificate.
+    fn on_select_client_certificate(
+        &self,
+        _browser: Browser,
+        _is_proxy: bool,
+        _host: CefString,
+        _port: u16,
+        _certificates: Vec<X509Certificate>,
+        _callback: SelectClientCertificateCallback,
+    ) -> bool {
+        false
+    }
+
+    /// Called on the browser process UI thread when the render process terminates unexpectedly.
+    fn on_render_process_terminated(
+        &self,
+        _browser: Browser,
+        _status: TerminationStatus,
+        _error_code: i32,
+        _error_string: CefString,
+    ) {
+    }
+
+    /// Called on the browser process UI thread when the window.document object of the main
+    /// frame has been created.
+    fn on_document_available_in_main_frame(&self, _browser: Browser) {}
+
+    fn get_raw(&self) -> *mut cef_request_handler_t {
+        let mut object: cef_request_handler_t = unsafe { std::mem::zeroed() };
+
+        object.on_before_browse = Some(on_before_browse::<Self>);
+        object.on_open_urlfrom_tab = Some(on_open_urlfrom_tab::<Self>);
+        object.get_resource_request_handler = Some(get_resource_request_handler::<Self>);
+        object.get_auth_credentials = Some(get_auth_credentials::<Self>);
+        object.on_certificate_error = Some(on_certificate_error::<Self>);
+        object.on_select_client_certificate = Some(on_select_client_certificate::<Self>);
+        object.on_render_process_terminated = Some(on_render_process_terminated::<Self>);
+        object.on_document_available_in_main_frame =
+            Some(on_document_available_in_main_frame::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl RequestHandler for () {
+    type ResourceRequestHandler = ();
+}
+
+extern "C" fn on_before_browse<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    user_gesture: ::std::os::raw::c_int,
+    is_redirect: ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let request = unsafe { Request::from_raw(request) };
+
+    handler
+        .interface
+        .on_before_browse(browser, frame, request, user_gesture > 0, is_redirect > 0)
+        .into()
+}
+
+extern "C" fn on_open_urlfrom_tab<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    target_url: *const cef_string_t,
+    target_disposition: cef_window_open_disposition_t,
+    user_gesture: ::std::os::raw::c_int,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let target_url = unsafe { CefString::from_raw(target_url).unwrap_or_default() };
+
+    handler
+        .interface
+        .on_open_urlfrom_tab(
+            browser,
+            frame,
+            target_url,
+            target_disposition,
+            user_gesture > 0,
+        )
+        .into()
+}
+
+extern "C" fn get_resource_request_handler<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    is_navigation: ::std::os::raw::c_int,
+    is_download: ::std::os::raw::c_int,
+    request_initiator: *const cef_string_t,
+    disable_default_handling: *mut ::std::os::raw::c_int,
+) -> *mut cef_resource_request_handler_t {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let frame = unsafe { Frame::from_raw(frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let request_initiator = unsafe { CefString::from_raw(request_initiator).unwrap_or_default() };
+    let mut disable = unsafe { *disable_default_handling } > 0;
+
+    let res = handler.interface.get_resource_request_handler(
+        browser,
+        frame,
+        request,
+        is_navigation > 0,
+        is_download > 0,
+        request_initiator,
+        &mut disable,
+    );
+    unsafe { *disable_default_handling = disable.into() };
+
+    match res {
+        Some(resource_request_handler) => resource_request_handler.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn get_auth_credentials<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    origin_url: *const cef_string_t,
+    is_proxy: ::std::os::raw::c_int,
+    host: *const cef_string_t,
+    port: ::std::os::raw::c_int,
+    realm: *const cef_string_t,
+    scheme: *const cef_string_t,
+    callback: *mut cef_auth_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let origin_url = unsafe { CefString::from_raw(origin_url).unwrap_or_default() };
+    let host = unsafe { CefString::from_raw(host).unwrap_or_default() };
+    let realm = unsafe { CefString::from_raw(realm).ok() };
+    let scheme = unsafe { CefString::from_raw(scheme).ok() };
+    let callback = unsafe { AuthCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .get_auth_credentials(
+            browser,
+            origin_url,
+            is_proxy > 0,
+            host,
+            port as u16,
+            realm,
+            scheme,
+            callback,
+        )
+        .into()
+}
+
+extern "C" fn on_certificate_error<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    cert_error: cef_errorcode_t,
+    request_url: *const cef_string_t,
+    ssl_info: *mut cef_sslinfo_t,
+    callback: *mut cef_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let request_url = unsafe { CefString::from_raw(request_url).unwrap_or_default() };
+    let ssl_info = unsafe { SslInfo::from_raw(ssl_info) };
+    let callback = unsafe { Callback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_certificate_error(browser, cert_error, request_url, ssl_info, callback)
+        .into()
+}
+
+extern "C" fn on_select_client_certificate<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    is_proxy: ::std::os::raw::c_int,
+    host: *const cef_string_t,
+    port: ::std::os::raw::c_int,
+    certificates_count: usize,
+    certificates: *const *mut cef_x509certificate_t,
+    callback: *mut cef_select_client_certificate_callback_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let host = unsafe { CefString::from_raw(host).unwrap_or_default() };
+    let certificates = if certificates.is_null() {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(certificates, certificates_count) }
+            .iter()
+            .map(|c| unsafe { X509Certificate::from_raw(*c) })
+            .collect()
+    };
+    let callback = unsafe { SelectClientCertificateCallback::from_raw(callback) };
+
+    handler
+        .interface
+        .on_select_client_certificate(
+            browser,
+            is_proxy > 0,
+            host,
+            port as u16,
+            certificates,
+            callback,
+        )
+        .into()
+}
+
+extern "C" fn on_render_process_terminated<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+    status: cef_termination_status_t,
+    error_code: ::std::os::raw::c_int,
+    error_string: *const cef_string_t,
+) {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+    let error_string = unsafe { CefString::from_raw(error_string).unwrap_or_default() };
+
+    handler
+        .interface
+        .on_render_process_terminated(browser, status, error_code, error_string);
+}
+
+extern "C" fn on_document_available_in_main_frame<R: RequestHandler>(
+    this: *mut cef_request_handler_t,
+    browser: *mut cef_browser_t,
+) {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let browser = unsafe { Browser::from_raw(browser) };
+
+    handler
+        .interface
+        .on_document_available_in_main_frame(browser);
+}
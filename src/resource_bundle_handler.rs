@@ -0,0 +1,104 @@
+use cef_sys::{cef_resource_bundle_handler_t, cef_scale_factor_t, cef_string_t};
+
+use crate::{rc::RcImpl, string::CefString};
+
+pub type ScaleFactor = cef_scale_factor_t;
+
+/// See [cef_resource_bundle_handler_t] for more documentation.
+///
+/// This handler is used in every process type, so it must behave the same in each of them.
+pub trait ResourceBundleHandler: Sized {
+    /// Return the localized string for the specified `string_id` or `None` to use the default
+    /// localized string.
+    fn get_localized_string(&self, _string_id: i32) -> Option<CefString> {
+        None
+    }
+
+    /// Return the data for the specified `resource_id` or `None` to use the default resource
+    /// data. The data is not copied and must stay resident in memory.
+    fn get_data_resource(&self, _resource_id: i32) -> Option<&'static [u8]> {
+        None
+    }
+
+    /// Return the data for the specified `resource_id` nearest the scale factor or `None` to use
+    /// the default resource data. The data is not copied and must stay resident in memory.
+    fn get_data_resource_for_scale(
+        &self,
+        _resource_id: i32,
+        _scale_factor: ScaleFactor,
+    ) -> Option<&'static [u8]> {
+        None
+    }
+
+    fn get_raw(&self) -> *mut cef_resource_bundle_handler_t {
+        let mut object: cef_resource_bundle_handler_t = unsafe { std::mem::zeroed() };
+
+        object.get_localized_string = Some(get_localized_string::<Self>);
+        object.get_data_resource = Some(get_data_resource::<Self>);
+        object.get_data_resource_for_scale = Some(get_data_resource_for_scale::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl ResourceBundleHandler for () {}
+
+extern "C" fn get_localized_string<R: ResourceBundleHandler>(
+    this: *mut cef_resource_bundle_handler_t,
+    string_id: ::std::os::raw::c_int,
+    string: *mut cef_string_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+
+    match handler.interface.get_localized_string(string_id) {
+        Some(value) => {
+            unsafe { value.set_raw(string) };
+            1
+        }
+        None => 0,
+    }
+}
+
+fn write_data(
+    data: Option<&'static [u8]>,
+    out: *mut *mut ::std::os::raw::c_void,
+    size: *mut usize,
+) -> ::std::os::raw::c_int {
+    match data {
+        Some(data) => {
+            unsafe {
+                *out = data.as_ptr() as *mut _;
+                *size = data.len();
+            }
+            1
+        }
+        None => 0,
+    }
+}
+
+extern "C" fn get_data_resource<R: ResourceBundleHandler>(
+    this: *mut cef_resource_bundle_handler_t,
+    resource_id: ::std::os::raw::c_int,
+    data: *mut *mut ::std::os::raw::c_void,
+    data_size: *mut usize,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let res = handler.interface.get_data_resource(resource_id);
+
+    write_data(res, data, data_size)
+}
+
+extern "C" fn get_data_resource_for_scale<R: ResourceBundleHandler>(
+    this: *mut cef_resource_bundle_handler_t,
+    resource_id: ::std::os::raw::c_int,
+    scale_factor: cef_scale_factor_t,
+    data: *mut *mut ::std::os::raw::c_void,
+    data_size: *mut usize,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let res = handler
+        .interface
+        .get_data_resource_for_scale(resource_id, scale_factor);
+
+    write_data(res, data, data_size)
+}
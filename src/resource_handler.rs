@@ -0,0 +1,369 @@
+use std::ffi::{c_int, c_void};
+
+use cef_sys::{
+    cef_base_ref_counted_t, cef_callback_t, cef_request_t, cef_resource_handler_t,
+    cef_resource_read_callback_t, cef_resource_skip_callback_t, cef_response_t, cef_string_t,
+};
+
+use crate::{
+    callback::Callback,
+    rc::{Rc, RcImpl, RefGuard},
+    request::Request,
+    response::Response,
+    string::CefString,
+    ErrorCode,
+};
+
+/// Return value of [ResourceHandler::open].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenResult {
+    /// Handle the request immediately.
+    Handle,
+    /// Decide later. Execute [Callback::cont] to handle the request or [Callback::cancel] to
+    /// cancel it.
+    Pending,
+    /// Cancel the request immediately.
+    Cancel,
+}
+
+/// Return value of [ResourceHandler::read].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadResult {
+    /// The given amount of bytes were copied to the output buffer.
+    Read(usize),
+    /// Data is not available yet. [ResourceHandler::read_pending] is called with the
+    /// [ResourceReadCallback] to execute once it is.
+    Pending,
+    /// The response is complete.
+    Complete,
+    /// The read failed.
+    Failed(ErrorCode),
+}
+
+/// Return value of [ResourceHandler::skip].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipResult {
+    /// The given amount of bytes were skipped.
+    Skipped(u64),
+    /// Skipping will complete later. Execute the [ResourceSkipCallback] once it does.
+    Pending,
+    /// The skip failed.
+    Failed(ErrorCode),
+}
+
+impl Rc for cef_resource_read_callback_t {
+    fn as_base(&self) -> &cef_base_ref_counted_t {
+        self.base.as_base()
+    }
+}
+
+/// See [cef_resource_read_callback_t] for more documentation.
+///
+/// This also holds the output buffer of the pending [ResourceHandler::read] call. The buffer
+/// stays valid until one of the methods consuming this callback is called, so the data can be
+/// provided from any thread. The callback must not be executed before
+/// [ResourceHandler::read_pending] returns.
+#[derive(Debug)]
+pub struct ResourceReadCallback {
+    callback: RefGuard<cef_resource_read_callback_t>,
+    data_out: *mut u8,
+    bytes_to_read: usize,
+}
+
+// The output buffer is owned by cef and stays valid until the callback is executed.
+unsafe impl Send for ResourceReadCallback {}
+
+impl ResourceReadCallback {
+    /// Maximum amount of bytes that can be provided to [ResourceReadCallback::cont].
+    pub fn bytes_to_read(&self) -> usize {
+        self.bytes_to_read
+    }
+
+    /// Copy `data` into the output buffer and continue the read. At most
+    /// [ResourceReadCallback::bytes_to_read] bytes are copied. Returns the amount of bytes copied.
+    pub fn cont(self, data: &[u8]) -> usize {
+        let len = data.len().min(self.bytes_to_read);
+        if len == 0 {
+            self.complete();
+            return 0;
+        }
+
+        unsafe { std::ptr::copy_nonoverlapping(data.as_ptr(), self.data_out, len) };
+        self.execute(len as c_int);
+        len
+    }
+
+    /// Indicate that the response is complete.
+    pub fn complete(self) {
+        self.execute(0);
+    }
+
+    /// Indicate that the read failed.
+    pub fn fail(self, error: ErrorCode) {
+        self.execute(error as c_int);
+    }
+
+    fn execute(&self, bytes_read: c_int) {
+        if let Some(f) = self.callback.cont {
+            unsafe { f(self.callback.get_raw(), bytes_read) };
+        }
+    }
+}
+
+impl Rc for cef_resource_skip_callback_t {
+    fn as_base(&self) -> &cef_base_ref_counted_t {
+        self.base.as_base()
+    }
+}
+
+/// See [cef_resource_skip_callback_t] for more documentation.
+#[derive(Debug, Clone)]
+pub struct ResourceSkipCallback(RefGuard<cef_resource_skip_callback_t>);
+
+impl ResourceSkipCallback {
+    /// Continue the skip with the amount of bytes skipped.
+    pub fn cont(self, bytes_skipped: u64) {
+        self.execute(bytes_skipped as i64);
+    }
+
+    /// Indicate that the skip failed.
+    pub fn fail(self, error: ErrorCode) {
+        self.execute(error as i64);
+    }
+
+    fn execute(&self, bytes_skipped: i64) {
+        if let Some(f) = self.0.cont {
+            unsafe { f(self.0.get_raw(), bytes_skipped) };
+        }
+    }
+}
+
+/// A single byte range parsed from a `Range` request header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteRange {
+    /// First byte of the range.
+    pub start: u64,
+    /// Last byte of the range, inclusive.
+    pub end: u64,
+}
+
+impl ByteRange {
+    /// Parse a `Range` header value like `bytes=0-499`, `bytes=500-` or `bytes=-500` against a
+    /// resource of `total_length` bytes. Returns `None` if the value is malformed, has multiple
+    /// ranges or can't be satisfied.
+    pub fn parse(value: &str, total_length: u64) -> Option<ByteRange> {
+        let spec = value.trim().strip_prefix("bytes=")?;
+        if spec.contains(',') || total_length == 0 {
+            return None;
+        }
+
+        let (start, end) = spec.split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+        let range = if start.is_empty() {
+            let suffix: u64 = end.parse().ok()?;
+            if suffix == 0 {
+                return None;
+            }
+            ByteRange {
+                start: total_length.saturating_sub(suffix),
+                end: total_length - 1,
+            }
+        } else {
+            let start: u64 = start.parse().ok()?;
+            let end = if end.is_empty() {
+                total_length - 1
+            } else {
+                end.parse::<u64>().ok()?.min(total_length - 1)
+            };
+            ByteRange { start, end }
+        };
+
+        (range.start <= range.end && range.start < total_length).then_some(range)
+    }
+
+    /// Amount of bytes in the range.
+    pub fn content_length(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Value of the `Content-Range` response header for this range.
+    pub fn content_range(&self, total_length: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, total_length)
+    }
+}
+
+/// See [cef_resource_handler_t] for more documentation.
+///
+/// The methods are called on the IO thread unless otherwise indicated.
+pub trait ResourceHandler: Sized {
+    /// Open the response stream. `request` may not be modified in this method.
+    fn open(&mut self, request: Request, callback: Callback) -> OpenResult;
+
+    /// Populate `response` with the response information. Return the response length, or `None`
+    /// if it is unknown. Set `redirect_url` to redirect the request to a new URL.
+    ///
+    /// To serve a byte range, set the status to 206 with a `Content-Range` header and return the
+    /// length of the range. cef then calls [ResourceHandler::skip] with the first byte of the
+    /// range before reading, so the stream must still start at the beginning of the full
+    /// response here and only be positioned by `skip`.
+    fn get_response_headers(
+        &mut self,
+        response: Response,
+        redirect_url: &mut Option<CefString>,
+    ) -> Option<u64>;
+
+    /// Skip response data when requested by a `Range` header. Skip over and discard
+    /// `bytes_to_skip` bytes of response data, which is the first byte of the range.
+    fn skip(&mut self, _bytes_to_skip: u64, _callback: ResourceSkipCallback) -> SkipResult {
+        SkipResult::Failed(ErrorCode::ERR_FAILED)
+    }
+
+    /// Read response data. Copy up to `data_out.len()` bytes into `data_out` and return
+    /// [ReadResult::Read] if data is available immediately. Otherwise return
+    /// [ReadResult::Pending] and provide the data later through the callback given to
+    /// [ResourceHandler::read_pending].
+    fn read(&mut self, data_out: &mut [u8]) -> ReadResult;
+
+    /// Called after [ResourceHandler::read] returned [ReadResult::Pending]. Keep `callback` and
+    /// execute it once data is available, possibly from another thread, but not before this
+    /// method returns. The request stalls if the callback is dropped without being executed.
+    fn read_pending(&mut self, _callback: ResourceReadCallback) {}
+
+    /// Request processing has been canceled.
+    fn cancel(&mut self) {}
+
+    fn into_raw(self) -> *mut cef_resource_handler_t {
+        let mut object: cef_resource_handler_t = unsafe { std::mem::zeroed() };
+
+        object.open = Some(open::<Self>);
+        object.get_response_headers = Some(get_response_headers::<Self>);
+        object.skip = Some(skip::<Self>);
+        object.read = Some(read::<Self>);
+        object.cancel = Some(cancel::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+/// Cancel every request. Used as the handler type of implementations that never handle requests.
+impl ResourceHandler for () {
+    fn open(&mut self, _request: Request, _callback: Callback) -> OpenResult {
+        OpenResult::Cancel
+    }
+
+    fn get_response_headers(
+        &mut self,
+        _response: Response,
+        _redirect_url: &mut Option<CefString>,
+    ) -> Option<u64> {
+        None
+    }
+
+    fn read(&mut self, _data_out: &mut [u8]) -> ReadResult {
+        ReadResult::Complete
+    }
+}
+
+extern "C" fn open<R: ResourceHandler>(
+    this: *mut cef_resource_handler_t,
+    request: *mut cef_request_t,
+    handle_request: *mut c_int,
+    callback: *mut cef_callback_t,
+) -> c_int {
+    let handler: &mut RcImpl<_, R> = RcImpl::get(this);
+    let request = unsafe { Request::from_raw(request) };
+    let callback = unsafe { Callback::from_raw(callback) };
+
+    let (handle, result) = match handler.interface.open(request, callback) {
+        OpenResult::Handle => (1, 1),
+        OpenResult::Pending => (0, 1),
+        OpenResult::Cancel => (1, 0),
+    };
+    unsafe { *handle_request = handle };
+
+    result
+}
+
+extern "C" fn get_response_headers<R: ResourceHandler>(
+    this: *mut cef_resource_handler_t,
+    response: *mut cef_response_t,
+    response_length: *mut i64,
+    redirect_url: *mut cef_string_t,
+) {
+    let handler: &mut RcImpl<_, R> = RcImpl::get(this);
+    let response = unsafe { Response::from_raw(response) };
+    let mut redirect = None;
+
+    let length = handler
+        .interface
+        .get_response_headers(response, &mut redirect);
+
+    unsafe {
+        *response_length = length.map(|l| l as i64).unwrap_or(-1);
+        if let Some(redirect) = redirect {
+            redirect.set_raw(redirect_url);
+        }
+    }
+}
+
+extern "C" fn skip<R: ResourceHandler>(
+    this: *mut cef_resource_handler_t,
+    bytes_to_skip: i64,
+    bytes_skipped: *mut i64,
+    callback: *mut cef_resource_skip_callback_t,
+) -> c_int {
+    let handler: &mut RcImpl<_, R> = RcImpl::get(this);
+    let callback = ResourceSkipCallback(unsafe { RefGuard::from_raw(callback) });
+
+    let (skipped, result) = match handler.interface.skip(bytes_to_skip as u64, callback) {
+        SkipResult::Skipped(skipped) => (skipped as i64, 1),
+        SkipResult::Pending => (0, 1),
+        SkipResult::Failed(error) => (error as i64, 0),
+    };
+    unsafe { *bytes_skipped = skipped };
+
+    result
+}
+
+extern "C" fn read<R: ResourceHandler>(
+    this: *mut cef_resource_handler_t,
+    data_out: *mut c_void,
+    bytes_to_read: c_int,
+    bytes_read: *mut c_int,
+    callback: *mut cef_resource_read_callback_t,
+) -> c_int {
+    let handler: &mut RcImpl<_, R> = RcImpl::get(this);
+    let bytes_to_read = if data_out.is_null() {
+        0
+    } else {
+        bytes_to_read.max(0) as usize
+    };
+    let buffer = if data_out.is_null() {
+        &mut [][..]
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(data_out as *mut u8, bytes_to_read) }
+    };
+
+    // The callback writes to the same buffer, so it is only created once the slice is gone.
+    let (read, result) = match handler.interface.read(buffer) {
+        ReadResult::Read(read) if read > 0 => (read.min(bytes_to_read) as c_int, 1),
+        ReadResult::Pending => {
+            handler.interface.read_pending(ResourceReadCallback {
+                callback: unsafe { RefGuard::from_raw(callback) },
+                data_out: data_out as *mut u8,
+                bytes_to_read,
+            });
+            (0, 1)
+        }
+        ReadResult::Read(_) | ReadResult::Complete => (0, 0),
+        ReadResult::Failed(error) => (error as c_int, 0),
+    };
+    unsafe { *bytes_read = read };
+
+    result
+}
+
+extern "C" fn cancel<R: ResourceHandler>(this: *mut cef_resource_handler_t) {
+    let handler: &mut RcImpl<_, R> = RcImpl::get(this);
+    handler.interface.cancel();
+}
@@ -0,0 +1,330 @@
+use std::ptr::null_mut;
+
+use cef_sys::{
+    cef_browser_t, cef_callback_t, cef_cookie_access_filter_t, cef_frame_t, cef_request_t,
+    cef_resource_handler_t, cef_resource_request_handler_t, cef_response_filter_t, cef_response_t,
+    cef_return_value_t, cef_string_t, cef_urlrequest_status_t,
+};
+
+use crate::{
+    callback::Callback, cookie_access_filter::CookieAccessFilter, frame::Frame, rc::RcImpl,
+    request::Request, resource_handler::ResourceHandler, response::Response,
+    response_filter::ResponseFilter, string::CefString, Browser, UrlRequestStatus,
+};
+
+/// Return value of [ResourceRequestHandler::on_before_resource_load].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeforeResourceLoadResult {
+    /// Continue the request immediately.
+    Continue,
+    /// Decide later. Execute [Callback::cont] to continue the request or [Callback::cancel] to
+    /// cancel it.
+    Pending,
+    /// Cancel the request immediately.
+    Cancel,
+}
+
+/// See [cef_resource_request_handler_t] for more documentation.
+///
+/// The methods are called on the IO thread unless otherwise indicated. `browser` and `frame`
+/// are `None` for requests originating from service workers or URL requests.
+pub trait ResourceRequestHandler: Sized {
+    type CookieAccessFilter: CookieAccessFilter;
+    type ResourceHandler: ResourceHandler;
+    type ResponseFilter: ResponseFilter;
+
+    /// Called before a resource request is sent to get the filter deciding which cookies can be
+    /// sent and saved. Return `None` to allow all cookies.
+    fn get_cookie_access_filter(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+    ) -> Option<&Self::CookieAccessFilter> {
+        None
+    }
+
+    /// Called before a resource request is loaded. `request` may be modified, like adding or
+    /// removing headers or changing the URL.
+    fn on_before_resource_load(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _callback: Callback,
+    ) -> BeforeResourceLoadResult {
+        BeforeResourceLoadResult::Continue
+    }
+
+    /// Called before a resource is loaded. Return a new resource handler to handle the request
+    /// from Rust instead of the network, or `None` to load it normally. `request` may not be
+    /// modified in this method.
+    fn get_resource_handler(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+    ) -> Option<Self::ResourceHandler> {
+        None
+    }
+
+    /// Called when a resource load is redirected. `request` contains the old URL and other
+    /// request-related information and `response` contains the response that resulted in the
+    /// redirect. `new_url` contains the new URL and can be changed if desired. `request` may not
+    /// be modified in this method.
+    fn on_resource_redirect(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _response: Response,
+        _new_url: &mut CefString,
+    ) {
+    }
+
+    /// Called when a resource response is received. To allow the resource load to proceed
+    /// without modification return `false`. To redirect or retry the resource load modify
+    /// `request` and return `true`. `response` may not be modified in this method.
+    fn on_resource_response(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _response: Response,
+    ) -> bool {
+        false
+    }
+
+    /// Called to optionally filter resource response content. Return a new filter instance or
+    /// `None` to leave the response unchanged. `request` and `response` may not be modified in
+    /// this method.
+    fn get_resource_response_filter(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _response: Response,
+    ) -> Option<Self::ResponseFilter> {
+        None
+    }
+
+    /// Called when a resource load has completed, whether it succeeded, failed or was canceled.
+    /// `received_content_length` is the number of response bytes actually read. `request` and
+    /// `response` may not be modified in this method.
+    fn on_resource_load_complete(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+        _response: Response,
+        _status: UrlRequestStatus,
+        _received_content_length: i64,
+    ) {
+    }
+
+    /// Called on the IO thread to handle requests for URLs with an unknown protocol component.
+    /// Return `true` to attempt execution via the registered OS protocol handler, if any.
+    fn on_protocol_execution(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _request: Request,
+    ) -> bool {
+        false
+    }
+
+    fn get_raw(&self) -> *mut cef_resource_request_handler_t {
+        let mut object: cef_resource_request_handler_t = unsafe { std::mem::zeroed() };
+
+        object.get_cookie_access_filter = Some(get_cookie_access_filter::<Self>);
+        object.on_before_resource_load = Some(on_before_resource_load::<Self>);
+        object.get_resource_handler = Some(get_resource_handler::<Self>);
+        object.on_resource_redirect = Some(on_resource_redirect::<Self>);
+        object.on_resource_response = Some(on_resource_response::<Self>);
+        object.get_resource_response_filter = Some(get_resource_response_filter::<Self>);
+        object.on_resource_load_complete = Some(on_resource_load_complete::<Self>);
+        object.on_protocol_execution = Some(on_protocol_execution::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+impl ResourceRequestHandler for () {
+    type CookieAccessFilter = ();
+    type ResourceHandler = ();
+    type ResponseFilter = ();
+}
+
+pub(crate) unsafe fn browser_and_frame(
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+) -> (Option<Browser>, Option<Frame>) {
+    let browser = (!browser.is_null()).then(|| unsafe { Browser::from_raw(browser) });
+    let frame = (!frame.is_null()).then(|| unsafe { Frame::from_raw(frame) });
+
+    (browser, frame)
+}
+
+extern "C" fn get_cookie_access_filter<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+) -> *mut cef_cookie_access_filter_t {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+
+    match handler
+        .interface
+        .get_cookie_access_filter(browser, frame, request)
+    {
+        Some(filter) => filter.get_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn on_before_resource_load<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    callback: *mut cef_callback_t,
+) -> cef_return_value_t {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let callback = unsafe { Callback::from_raw(callback) };
+
+    match handler
+        .interface
+        .on_before_resource_load(browser, frame, request, callback)
+    {
+        BeforeResourceLoadResult::Continue => cef_return_value_t::RV_CONTINUE,
+        BeforeResourceLoadResult::Pending => cef_return_value_t::RV_CONTINUE_ASYNC,
+        BeforeResourceLoadResult::Cancel => cef_return_value_t::RV_CANCEL,
+    }
+}
+
+extern "C" fn get_resource_handler<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+) -> *mut cef_resource_handler_t {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+
+    match handler
+        .interface
+        .get_resource_handler(browser, frame, request)
+    {
+        Some(resource_handler) => resource_handler.into_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn on_resource_redirect<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    response: *mut cef_response_t,
+    new_url: *mut cef_string_t,
+) {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let response = unsafe { Response::from_raw(response) };
+    let mut url = unsafe { CefString::from_raw(new_url).unwrap_or_default() };
+    let old_url = url.clone();
+
+    handler
+        .interface
+        .on_resource_redirect(browser, frame, request, response, &mut url);
+
+    if url.0 != old_url.0 {
+        unsafe { url.set_raw(new_url) };
+    }
+}
+
+extern "C" fn on_resource_response<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    response: *mut cef_response_t,
+) -> ::std::os::raw::c_int {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let response = unsafe { Response::from_raw(response) };
+
+    handler
+        .interface
+        .on_resource_response(browser, frame, request, response)
+        .into()
+}
+
+extern "C" fn get_resource_response_filter<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    response: *mut cef_response_t,
+) -> *mut cef_response_filter_t {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let response = unsafe { Response::from_raw(response) };
+
+    match handler
+        .interface
+        .get_resource_response_filter(browser, frame, request, response)
+    {
+        Some(filter) => filter.into_raw(),
+        None => null_mut(),
+    }
+}
+
+extern "C" fn on_resource_load_complete<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    response: *mut cef_response_t,
+    status: cef_urlrequest_status_t,
+    received_content_length: i64,
+) {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+    let response = unsafe { Response::from_raw(response) };
+
+    handler.interface.on_resource_load_complete(
+        browser,
+        frame,
+        request,
+        response,
+        status,
+        received_content_length,
+    );
+}
+
+extern "C" fn on_protocol_execution<R: ResourceRequestHandler>(
+    this: *mut cef_resource_request_handler_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    request: *mut cef_request_t,
+    allow_os_execution: *mut ::std::os::raw::c_int,
+) {
+    let handler: &mut RcImpl<_, &R> = RcImpl::get(this);
+    let (browser, frame) = unsafe { browser_and_frame(browser, frame) };
+    let request = unsafe { Request::from_raw(request) };
+
+    let allow = handler
+        .interface
+        .on_protocol_execution(browser, frame, request);
+    unsafe { *allow_os_execution = allow.into() };
+}
@@ -0,0 +1,192 @@
+use cef_sys::{cef_response_create, cef_response_t};
+
+use crate::{
+    string::{CefString, CefStringError, CefStringMultimap},
+    wrapper, ErrorCode,
+};
+
+wrapper!(
+    #[doc = "See [cef_response_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct Response(cef_response_t);
+);
+
+impl Default for Response {
+    fn default() -> Self {
+        unsafe { Response::from_raw(cef_response_create()) }
+    }
+}
+
+impl Response {
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    /// Returns the response error code or `ERR_NONE` if there was no error.
+    pub fn get_error(&self) -> ErrorCode {
+        self.0
+            .get_error
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ErrorCode::ERR_NONE)
+    }
+
+    pub fn set_error(&self, error: ErrorCode) {
+        if let Some(f) = self.0.set_error {
+            unsafe { f(self.0.get_raw(), error) };
+        }
+    }
+
+    pub fn get_status(&self) -> i32 {
+        self.0
+            .get_status
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn set_status(&self, status: i32) {
+        if let Some(f) = self.0.set_status {
+            unsafe { f(self.0.get_raw(), status) };
+        }
+    }
+
+    pub fn get_status_text(&self) -> Result<CefString, CefStringError> {
+        let get_status_text = self.0.get_status_text.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_status_text(self.0.get_raw())) }
+    }
+
+    pub fn set_status_text(&self, status_text: CefString) {
+        if let Some(f) = self.0.set_status_text {
+            unsafe { f(self.0.get_raw(), &status_text.get_raw()) };
+        }
+    }
+
+    pub fn get_mime_type(&self) -> Result<CefString, CefStringError> {
+        let get_mime_type = self.0.get_mime_type.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_mime_type(self.0.get_raw())) }
+    }
+
+    pub fn set_mime_type(&self, mime_type: CefString) {
+        if let Some(f) = self.0.set_mime_type {
+            unsafe { f(self.0.get_raw(), &mime_type.get_raw()) };
+        }
+    }
+
+    pub fn get_charset(&self) -> Result<CefString, CefStringError> {
+        let get_charset = self.0.get_charset.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_charset(self.0.get_raw())) }
+    }
+
+    pub fn set_charset(&self, charset: CefString) {
+        if let Some(f) = self.0.set_charset {
+            unsafe { f(self.0.get_raw(), &charset.get_raw()) };
+        }
+    }
+
+    /// Returns the first header value for `name` or an error if the header is not found.
+    pub fn get_header_by_name(&self, name: CefString) -> Result<CefString, CefStringError> {
+        let get_header_by_name = self.0.get_header_by_name.unwrap();
+
+        unsafe {
+            CefString::from_userfree_cef(get_header_by_name(self.0.get_raw(), &name.get_raw()))
+        }
+    }
+
+    /// Set the header `name` to `value`. If `overwrite` is `true` any existing values will be
+    /// replaced with the new value. If `overwrite` is `false` any existing values will not be
+    /// overwritten.
+    pub fn set_header_by_name(&self, name: CefString, value: CefString, overwrite: bool) {
+        if let Some(f) = self.0.set_header_by_name {
+            unsafe {
+                f(
+                    self.0.get_raw(),
+                    &name.get_raw(),
+                    &value.get_raw(),
+                    overwrite.into(),
+                )
+            };
+        }
+    }
+
+    /// Returns all headers. A header name can appear multiple times.
+    pub fn get_header_map(&self) -> CefStringMultimap {
+        let mut headers = CefStringMultimap::default();
+        if let Some(f) = self.0.get_header_map {
+            unsafe { f(self.0.get_raw(), headers.get_raw_mut()) };
+        }
+        headers
+    }
+
+    /// Replace all headers.
+    pub fn set_header_map(&self, mut headers: CefStringMultimap) {
+        if let Some(f) = self.0.set_header_map {
+            unsafe { f(self.0.get_raw(), headers.get_raw_mut()) };
+        }
+    }
+
+    /// Returns the resolved URL after redirects or changes as a result of HSTS.
+    pub fn get_url(&self) -> Result<CefString, CefStringError> {
+        let get_url = self.0.get_url.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_url(self.0.get_raw())) }
+    }
+
+    pub fn set_url(&self, url: CefString) {
+        if let Some(f) = self.0.set_url {
+            unsafe { f(self.0.get_raw(), &url.get_raw()) };
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+impl TryFrom<&Response> for http::Response<()> {
+    type Error = http::Error;
+
+    /// The body is not part of [Response] and is left empty.
+    fn try_from(response: &Response) -> Result<Self, Self::Error> {
+        let mut builder = http::Response::builder().status(response.get_status() as u16);
+
+        for (name, value) in response.get_header_map().to_vec() {
+            builder = builder.header(name.to_string(), value.to_string());
+        }
+
+        builder.body(())
+    }
+}
+
+#[cfg(feature = "http")]
+impl<B> From<&http::Response<B>> for Response {
+    /// Header values that are not valid UTF-8 are skipped. The MIME type and charset are taken
+    /// from the `Content-Type` header. The body is not part of [Response] and is ignored.
+    fn from(response: &http::Response<B>) -> Self {
+        let res = Response::default();
+        let status = response.status();
+        res.set_status(status.as_u16() as i32);
+        res.set_status_text(CefString::from(status.canonical_reason().unwrap_or("")));
+
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+            .collect();
+        res.set_header_map(headers);
+
+        if let Some(content_type) = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+        {
+            let mut parts = content_type.split(';');
+            res.set_mime_type(CefString::from(parts.next().unwrap_or("").trim()));
+            if let Some(charset) = parts.find_map(|p| p.trim().strip_prefix("charset=")) {
+                res.set_charset(CefString::from(charset.trim_matches('"')));
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,348 @@
+use std::ffi::c_void;
+
+use cef_sys::cef_response_filter_t;
+
+use crate::{rc::RcImpl, ResponseFilterStatus};
+
+/// See [cef_response_filter_t] for more documentation.
+///
+/// The methods are called on the browser process IO thread.
+pub trait ResponseFilter: Sized {
+    /// Initialize the filter. Return `false` if the filter will not be installed.
+    fn init_filter(&mut self) -> bool {
+        true
+    }
+
+    /// Called to filter a chunk of data. Read from `data_in` and set `data_in_read` to the amount
+    /// of bytes read. Write the filtered output to `data_out` and set `data_out_written` to the
+    /// amount of bytes written.
+    ///
+    /// Return [ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA] if filtered output is still
+    /// pending, [ResponseFilterStatus::RESPONSE_FILTER_DONE] once all available output has been
+    /// written or [ResponseFilterStatus::RESPONSE_FILTER_ERROR] to fail the request. This is
+    /// called repeatedly until all input has been read and the filter returns done. `data_in`
+    /// is empty once the end of the response has been reached.
+    fn filter(
+        &mut self,
+        data_in: &[u8],
+        data_in_read: &mut usize,
+        data_out: &mut [u8],
+        data_out_written: &mut usize,
+    ) -> ResponseFilterStatus;
+
+    fn into_raw(self) -> *mut cef_response_filter_t {
+        let mut object: cef_response_filter_t = unsafe { std::mem::zeroed() };
+
+        object.init_filter = Some(init_filter::<Self>);
+        object.filter = Some(filter::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+/// Pass the response through unchanged.
+impl ResponseFilter for () {
+    fn filter(
+        &mut self,
+        data_in: &[u8],
+        data_in_read: &mut usize,
+        data_out: &mut [u8],
+        data_out_written: &mut usize,
+    ) -> ResponseFilterStatus {
+        let len = data_in.len().min(data_out.len());
+        data_out[..len].copy_from_slice(&data_in[..len]);
+        *data_in_read = len;
+        *data_out_written = len;
+
+        if len < data_in.len() {
+            ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA
+        } else {
+            ResponseFilterStatus::RESPONSE_FILTER_DONE
+        }
+    }
+}
+
+/// A [ResponseFilter] that replaces every occurrence of a byte pattern in the response body.
+///
+/// Matches spanning chunk boundaries are found as well. Since the length of the body may change,
+/// the `Content-Length` of the response can't be trusted once the filter is installed.
+#[derive(Debug, Clone, Default)]
+pub struct FindReplaceFilter {
+    find: Vec<u8>,
+    replace: Vec<u8>,
+    /// Input that may be the beginning of a match and needs more data to decide.
+    carry: Vec<u8>,
+    /// Filtered output that didn't fit in the output buffer yet.
+    overflow: Vec<u8>,
+}
+
+impl FindReplaceFilter {
+    /// Replace every occurrence of `find` with `replace`. An empty `find` leaves the response
+    /// unchanged.
+    pub fn new(find: impl Into<Vec<u8>>, replace: impl Into<Vec<u8>>) -> Self {
+        Self {
+            find: find.into(),
+            replace: replace.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Process `buffer` into the overflow, keeping back the longest tail that is a prefix of the
+    /// pattern.
+    fn process(&mut self, buffer: &[u8]) {
+        let find = &self.find;
+        let mut start = 0;
+        let mut i = 0;
+        while i + find.len() <= buffer.len() {
+            if buffer[i..].starts_with(find) {
+                self.overflow.extend_from_slice(&buffer[start..i]);
+                self.overflow.extend_from_slice(&self.replace);
+                i += find.len();
+                start = i;
+            } else {
+                i += 1;
+            }
+        }
+
+        let tail = &buffer[start..];
+        let keep = (1..find.len().min(tail.len() + 1))
+            .rev()
+            .find(|n| find.starts_with(&tail[tail.len() - n..]))
+            .unwrap_or(0);
+        self.overflow.extend_from_slice(&tail[..tail.len() - keep]);
+        self.carry = tail[tail.len() - keep..].to_vec();
+    }
+}
+
+impl ResponseFilter for FindReplaceFilter {
+    fn filter(
+        &mut self,
+        data_in: &[u8],
+        data_in_read: &mut usize,
+        data_out: &mut [u8],
+        data_out_written: &mut usize,
+    ) -> ResponseFilterStatus {
+        if self.find.is_empty() {
+            return ().filter(data_in, data_in_read, data_out, data_out_written);
+        }
+
+        if data_in.is_empty() {
+            // Once pending output is drained, empty input means the end of the response and a
+            // partial match can't be completed anymore.
+            if self.overflow.is_empty() {
+                let carry = std::mem::take(&mut self.carry);
+                self.overflow.extend_from_slice(&carry);
+            }
+        } else {
+            let mut buffer = std::mem::take(&mut self.carry);
+            buffer.extend_from_slice(data_in);
+            self.process(&buffer);
+        }
+        *data_in_read = data_in.len();
+
+        let len = self.overflow.len().min(data_out.len());
+        data_out[..len].copy_from_slice(&self.overflow[..len]);
+        self.overflow.drain(..len);
+        *data_out_written = len;
+
+        if !self.overflow.is_empty() || !self.carry.is_empty() {
+            ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA
+        } else {
+            ResponseFilterStatus::RESPONSE_FILTER_DONE
+        }
+    }
+}
+
+extern "C" fn init_filter<F: ResponseFilter>(
+    this: *mut cef_response_filter_t,
+) -> ::std::os::raw::c_int {
+    let filter: &mut RcImpl<_, F> = RcImpl::get(this);
+    filter.interface.init_filter().into()
+}
+
+extern "C" fn filter<F: ResponseFilter>(
+    this: *mut cef_response_filter_t,
+    data_in: *mut c_void,
+    data_in_size: usize,
+    data_in_read: *mut usize,
+    data_out: *mut c_void,
+    data_out_size: usize,
+    data_out_written: *mut usize,
+) -> ResponseFilterStatus {
+    let filter: &mut RcImpl<_, F> = RcImpl::get(this);
+    let data_in = if data_in.is_null() {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data_in as *const u8, data_in_size) }
+    };
+    let data_out = if data_out.is_null() {
+        &mut [][..]
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(data_out as *mut u8, data_out_size) }
+    };
+    let (mut read, mut written) = (0, 0);
+
+    let status = filter
+        .interface
+        .filter(data_in, &mut read, data_out, &mut written);
+
+    unsafe {
+        *data_in_read = read.min(data_in.len());
+        *data_out_written = written.min(data_out.len());
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `filter` like cef does, with the input split into `chunks` and an output buffer of
+    /// `out_size` bytes.
+    fn run(filter: &mut impl ResponseFilter, chunks: &[&[u8]], out_size: usize) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut data_out = vec![0; out_size];
+        let mut call = |data_in: &[u8], output: &mut Vec<u8>| {
+            let (mut read, mut written) = (0, 0);
+            let status = filter.filter(data_in, &mut read, &mut data_out, &mut written);
+            assert!(written <= out_size);
+            output.extend_from_slice(&data_out[..written]);
+            (read, status)
+        };
+
+        for mut chunk in chunks.iter().copied() {
+            while !chunk.is_empty() {
+                let (read, _) = call(chunk, &mut output);
+                chunk = &chunk[read..];
+            }
+        }
+        for _ in 0..1000 {
+            if call(&[], &mut output).1 == ResponseFilterStatus::RESPONSE_FILTER_DONE {
+                return output;
+            }
+        }
+        panic!("the filter is not done after the end of the response");
+    }
+
+    fn replace_all(input: &[u8], find: &[u8], replace: &[u8]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut i = 0;
+        while i < input.len() {
+            if input[i..].starts_with(find) {
+                output.extend_from_slice(replace);
+                i += find.len();
+            } else {
+                output.push(input[i]);
+                i += 1;
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn match_split_across_chunks() {
+        let mut filter = FindReplaceFilter::new("needle", "pin");
+        let output = run(&mut filter, &[b"hay nee", b"dle hay"], 64);
+        assert_eq!(output, b"hay pin hay");
+
+        let mut filter = FindReplaceFilter::new("needle", "pin");
+        let output = run(&mut filter, &[b"ne", b"ed", b"le", b"needl", b"e"], 64);
+        assert_eq!(output, b"pinpin");
+    }
+
+    #[test]
+    fn partial_match_is_kept_until_the_next_chunk() {
+        let mut filter = FindReplaceFilter::new("needle", "pin");
+        let mut data_out = [0; 64];
+        let (mut read, mut written) = (0, 0);
+
+        let status = filter.filter(b"hay nee", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA);
+        assert_eq!(read, 7);
+        assert_eq!(&data_out[..written], b"hay ");
+
+        let status = filter.filter(b"d", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA);
+        assert_eq!(written, 0);
+
+        let status = filter.filter(b"x", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_DONE);
+        assert_eq!(&data_out[..written], b"needx");
+    }
+
+    #[test]
+    fn replacement_longer_than_the_output_buffer() {
+        let mut filter = FindReplaceFilter::new("a", "0123456789");
+        let mut data_out = [0; 4];
+        let (mut read, mut written) = (0, 0);
+
+        let status = filter.filter(b"bab", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA);
+        assert_eq!(read, 3);
+        assert_eq!(&data_out[..written], b"b012");
+
+        let mut filter = FindReplaceFilter::new("a", "0123456789");
+        let output = run(&mut filter, &[b"bab", b"aa"], 4);
+        assert_eq!(output, b"b0123456789b01234567890123456789");
+    }
+
+    #[test]
+    fn end_of_stream_flushes_a_partial_match() {
+        let mut filter = FindReplaceFilter::new("needle", "pin");
+        let mut data_out = [0; 64];
+        let (mut read, mut written) = (0, 0);
+
+        let status = filter.filter(b"hello nee", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_NEED_MORE_DATA);
+        assert_eq!(&data_out[..written], b"hello ");
+
+        let status = filter.filter(b"", &mut read, &mut data_out, &mut written);
+        assert_eq!(status, ResponseFilterStatus::RESPONSE_FILTER_DONE);
+        assert_eq!(read, 0);
+        assert_eq!(&data_out[..written], b"nee");
+
+        let mut filter = FindReplaceFilter::new("needle", "a much longer replacement");
+        let output = run(&mut filter, &[b"needle nee"], 3);
+        assert_eq!(output, b"a much longer replacement nee");
+    }
+
+    #[test]
+    fn overlapping_prefixes() {
+        let mut filter = FindReplaceFilter::new("aab", "X");
+        assert_eq!(run(&mut filter, &[b"aa", b"ab"], 64), b"aX");
+
+        let mut filter = FindReplaceFilter::new("abab", "X");
+        assert_eq!(run(&mut filter, &[b"aba", b"bab", b"ab"], 64), b"XX");
+
+        let mut filter = FindReplaceFilter::new("abab", "X");
+        assert_eq!(run(&mut filter, &[b"aba", b"bab"], 64), b"Xab");
+    }
+
+    #[test]
+    fn empty_pattern_passes_the_response_through() {
+        let mut filter = FindReplaceFilter::new("", "X");
+        assert_eq!(run(&mut filter, &[b"abc", b"def"], 2), b"abcdef");
+    }
+
+    #[test]
+    fn every_chunk_and_output_size() {
+        let input = b"the needle and the needlneedle, nee";
+        let expected = replace_all(input, b"needle", b"<pin>");
+
+        for split in 0..=input.len() {
+            for step in 1..=input.len() {
+                let (head, tail) = input.split_at(split);
+                let mut chunks: Vec<&[u8]> = vec![head];
+                chunks.extend(tail.chunks(step));
+                for out_size in 1..8 {
+                    let mut filter = FindReplaceFilter::new("needle", "<pin>");
+                    let output = run(&mut filter, &chunks, out_size);
+                    assert_eq!(
+                        output, expected,
+                        "split {split}, step {step}, output size {out_size}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::ptr::{null, null_mut};
+
+use bitflags::bitflags;
+use cef_sys::{
+    cef_browser_t, cef_clear_scheme_handler_factories, cef_frame_t,
+    cef_register_scheme_handler_factory, cef_request_t, cef_resource_handler_t,
+    cef_scheme_handler_factory_t, cef_scheme_options_t, cef_scheme_registrar_t, cef_string_t,
+};
+
+use crate::{
+    frame::Frame, rc::RcImpl, request::Request, resource_handler::ResourceHandler,
+    string::CefString, Browser,
+};
+
+bitflags! {
+    /// Configuration options for registering a custom scheme. See [cef_scheme_options_t] for more
+    /// documentation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SchemeOptions: i32 {
+        /// Treat the scheme as a standard scheme with `scheme://authority/path` URLs.
+        const STANDARD = cef_scheme_options_t::CEF_SCHEME_OPTION_STANDARD as i32;
+        /// Treat the scheme with the same security rules as `file` URLs.
+        const LOCAL = cef_scheme_options_t::CEF_SCHEME_OPTION_LOCAL as i32;
+        /// Only pages of the same scheme can display or link to the scheme.
+        const DISPLAY_ISOLATED = cef_scheme_options_t::CEF_SCHEME_OPTION_DISPLAY_ISOLATED as i32;
+        /// Treat the scheme with the same security rules as `https` URLs.
+        const SECURE = cef_scheme_options_t::CEF_SCHEME_OPTION_SECURE as i32;
+        /// The scheme can be sent CORS requests.
+        const CORS_ENABLED = cef_scheme_options_t::CEF_SCHEME_OPTION_CORS_ENABLED as i32;
+        /// The scheme can bypass Content-Security-Policy checks.
+        const CSP_BYPASSING = cef_scheme_options_t::CEF_SCHEME_OPTION_CSP_BYPASSING as i32;
+        /// The scheme can perform Fetch API requests.
+        const FETCH_ENABLED = cef_scheme_options_t::CEF_SCHEME_OPTION_FETCH_ENABLED as i32;
+    }
+}
+
+/// See [cef_scheme_registrar_t] for more documentation.
+///
+/// The registrar is only valid for the duration of [App::on_register_custom_schemes].
+///
+/// [App::on_register_custom_schemes]: crate::App::on_register_custom_schemes
+#[derive(Debug)]
+pub struct SchemeRegistrar(*mut cef_scheme_registrar_t);
+
+impl SchemeRegistrar {
+    pub(crate) fn from_mut_ptr(raw: *mut cef_scheme_registrar_t) -> Self {
+        Self(raw)
+    }
+
+    /// Register a custom scheme. This must be done with the same `options` in every process
+    /// type. Returns `false` if the scheme is already registered or an error occurred.
+    pub fn add_custom_scheme(&mut self, scheme_name: CefString, options: SchemeOptions) -> bool {
+        unsafe {
+            (*self.0)
+                .add_custom_scheme
+                .map(|f| f(self.0, &scheme_name.get_raw(), options.bits()) > 0)
+                .unwrap_or(false)
+        }
+    }
+}
+
+/// See [cef_scheme_handler_factory_t] for more documentation.
+pub trait SchemeHandlerFactory: Sized {
+    type ResourceHandler: ResourceHandler;
+
+    /// Return a new resource handler instance to handle the request or `None` to allow default
+    /// handling of the request. `browser` and `frame` will be `None` if the request did not
+    /// originate from a browser window. This method is called on the IO thread.
+    fn create(
+        &self,
+        browser: Option<Browser>,
+        frame: Option<Frame>,
+        scheme_name: CefString,
+        request: Request,
+    ) -> Option<Self::ResourceHandler>;
+
+    fn into_raw(self) -> *mut cef_scheme_handler_factory_t {
+        let mut object: cef_scheme_handler_factory_t = unsafe { std::mem::zeroed() };
+
+        object.create = Some(create::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+extern "C" fn create<F: SchemeHandlerFactory>(
+    this: *mut cef_scheme_handler_factory_t,
+    browser: *mut cef_browser_t,
+    frame: *mut cef_frame_t,
+    scheme_name: *const cef_string_t,
+    request: *mut cef_request_t,
+) -> *mut cef_resource_handler_t {
+    let factory: &mut RcImpl<_, F> = RcImpl::get(this);
+    let browser = (!browser.is_null()).then(|| unsafe { Browser::from_raw(browser) });
+    let frame = (!frame.is_null()).then(|| unsafe { Frame::from_raw(frame) });
+    let scheme_name = unsafe { CefString::from_raw(scheme_name).unwrap_or_default() };
+    let request = unsafe { Request::from_raw(request) };
+
+    match factory
+        .interface
+        .create(browser, frame, scheme_name, request)
+    {
+        Some(handler) => handler.into_raw(),
+        None => null_mut(),
+    }
+}
+
+/// See [cef_register_scheme_handler_factory] for more documentation.
+///
+/// `domain_name` is ignored for non-standard schemes. If it is `None` the factory will match all
+/// domain names.
+pub fn register_scheme_handler_factory<F: SchemeHandlerFactory>(
+    scheme_name: CefString,
+    domain_name: Option<CefString>,
+    factory: F,
+) -> bool {
+    let domain_name = domain_name.as_ref().map(|d| d.get_raw());
+
+    unsafe {
+        cef_register_scheme_handler_factory(
+            &scheme_name.get_raw(),
+            domain_name
+                .as_ref()
+                .map(|d| d as *const _)
+                .unwrap_or(null()),
+            factory.into_raw(),
+        ) > 0
+    }
+}
+
+/// See [cef_clear_scheme_handler_factories] for more documentation.
+pub fn clear_scheme_handler_factories() -> bool {
+    unsafe { cef_clear_scheme_handler_factories() > 0 }
+}
@@ -0,0 +1,90 @@
+use std::time::SystemTime;
+
+use cef_sys::{cef_sslinfo_t, cef_x509certificate_t};
+
+use crate::{utils::basetime_to_system_time, values::BinaryValue, wrapper};
+
+wrapper!(
+    #[doc = "See [cef_sslinfo_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct SslInfo(cef_sslinfo_t);
+);
+
+impl SslInfo {
+    pub fn get_x509_certificate(&self) -> Option<X509Certificate> {
+        self.0.get_x509certificate.and_then(|f| {
+            let p = unsafe { f(self.0.get_raw()) };
+            if p.is_null() {
+                None
+            } else {
+                Some(unsafe { X509Certificate::from_raw(p) })
+            }
+        })
+    }
+}
+
+// TO DO: Implement get_cert_status once cef_cert_status_t is a bitflags type
+
+wrapper!(
+    #[doc = "See [cef_x509certificate_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct X509Certificate(cef_x509certificate_t);
+);
+
+impl X509Certificate {
+    pub fn get_serial_number(&self) -> Option<BinaryValue> {
+        self.0.get_serial_number.and_then(|f| {
+            let p = unsafe { f(self.0.get_raw()) };
+            if p.is_null() {
+                None
+            } else {
+                Some(unsafe { BinaryValue::from_raw(p) })
+            }
+        })
+    }
+
+    pub fn get_valid_start(&self) -> Option<SystemTime> {
+        self.0
+            .get_valid_start
+            .and_then(|f| basetime_to_system_time(unsafe { f(self.0.get_raw()) }))
+    }
+
+    pub fn get_valid_expiry(&self) -> Option<SystemTime> {
+        self.0
+            .get_valid_expiry
+            .and_then(|f| basetime_to_system_time(unsafe { f(self.0.get_raw()) }))
+    }
+
+    /// Returns the DER encoded data for the X.509 certificate.
+    pub fn get_der_encoded(&self) -> Option<BinaryValue> {
+        self.0.get_derencoded.and_then(|f| {
+            let p = unsafe { f(self.0.get_raw()) };
+            if p.is_null() {
+                None
+            } else {
+                Some(unsafe { BinaryValue::from_raw(p) })
+            }
+        })
+    }
+
+    /// Returns the PEM encoded data for the X.509 certificate.
+    pub fn get_pem_encoded(&self) -> Option<BinaryValue> {
+        self.0.get_pemencoded.and_then(|f| {
+            let p = unsafe { f(self.0.get_raw()) };
+            if p.is_null() {
+                None
+            } else {
+                Some(unsafe { BinaryValue::from_raw(p) })
+            }
+        })
+    }
+
+    pub fn get_issuer_chain_size(&self) -> usize {
+        self.0
+            .get_issuer_chain_size
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+}
+
+// TO DO: Implement get_subject and get_issuer with cef_x509cert_principal_t
@@ -0,0 +1,818 @@
+//! Static asset module
+//!
+//! [`StaticAssetHandler`] serves files for a custom scheme like `app://` from a directory on
+//! disk or from assets embedded in the binary. It takes care of the usual details of serving
+//! static files:
+//!
+//! - MIME type detection from the file extension.
+//! - `ETag` and `If-None-Match` revalidation.
+//! - Single `Range` requests.
+//! - Precompressed `.br` and `.gz` variants when the request accepts them.
+//! - Index files for directories and an optional fallback file for single page applications.
+//!
+//! List the custom scheme in [`App::get_custom_schemes`](crate::App::get_custom_schemes) so it is
+//! added in every process, and register the handler from
+//! [`App::register_scheme_handler_factories`](crate::App::register_scheme_handler_factories),
+//! which only runs in the browser process:
+//!
+//! ```no_run
+//! use cef::{scheme::SchemeOptions, static_asset::StaticAssetHandler, string::CefString, App};
+//!
+//! struct MyApp;
+//!
+//! impl App for MyApp {
+//!     type RenderProcessHandler = ();
+//!     type BrowserProcessHandler = ();
+//!     type ResourceBundleHandler = ();
+//!
+//!     fn get_custom_schemes(&self) -> Vec<(String, SchemeOptions)> {
+//!         vec![("app".to_owned(), StaticAssetHandler::SCHEME_OPTIONS)]
+//!     }
+//!
+//!     fn register_scheme_handler_factories(&self) {
+//!         StaticAssetHandler::from_directory("dist").register(CefString::new("app"), None);
+//!     }
+//! }
+//! ```
+//!
+//! Other options can be returned in place of [`StaticAssetHandler::SCHEME_OPTIONS`], but the
+//! scheme must be standard for the handler to see the paths of its URLs.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{Read, Seek, SeekFrom},
+    path::{Component, Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use crate::{
+    callback::Callback,
+    frame::Frame,
+    request::Request,
+    resource_handler::{
+        ByteRange, OpenResult, ReadResult, ResourceHandler, ResourceSkipCallback, SkipResult,
+    },
+    response::Response,
+    scheme::{register_scheme_handler_factory, SchemeHandlerFactory, SchemeOptions},
+    string::CefString,
+    Browser, ErrorCode,
+};
+
+/// Where a [StaticAssetHandler] loads its files from.
+#[derive(Debug, Clone)]
+pub enum AssetSource {
+    /// Files below a directory on disk.
+    Directory(PathBuf),
+    /// Files embedded in the binary, for example with [include_bytes], keyed by their path
+    /// relative to the root without a leading `/`.
+    Embedded(HashMap<String, &'static [u8]>),
+    /// Files embedded in the binary with the `include_dir` crate.
+    #[cfg(feature = "include_dir")]
+    IncludeDir(&'static include_dir::Dir<'static>),
+}
+
+/// A [SchemeHandlerFactory] serving static files. See the [module documentation](self) for more
+/// information.
+#[derive(Debug, Clone)]
+pub struct StaticAssetHandler {
+    pub source: AssetSource,
+    /// File served for requests to a directory.
+    pub index_file: String,
+    /// File served with status 200 for requests that don't match any file and don't have a file
+    /// extension. Set this to the entry point of a single page application.
+    pub spa_fallback: Option<String>,
+    /// Serve `<file>.br` and `<file>.gz` variants if they exist and the request accepts them.
+    pub precompressed: bool,
+    /// Value of the `Cache-Control` response header.
+    pub cache_control: Option<String>,
+}
+
+impl StaticAssetHandler {
+    /// The options to add the custom scheme of the handler with, for example from
+    /// [App::get_custom_schemes](crate::App::get_custom_schemes), so that pages served by the
+    /// handler behave like https pages.
+    pub const SCHEME_OPTIONS: SchemeOptions = SchemeOptions::STANDARD
+        .union(SchemeOptions::SECURE)
+        .union(SchemeOptions::CORS_ENABLED)
+        .union(SchemeOptions::FETCH_ENABLED);
+
+    pub fn new(source: AssetSource) -> Self {
+        Self {
+            source,
+            index_file: "index.html".to_owned(),
+            spa_fallback: None,
+            precompressed: true,
+            cache_control: Some("no-cache".to_owned()),
+        }
+    }
+
+    /// Serve files below `root`.
+    pub fn from_directory(root: impl Into<PathBuf>) -> Self {
+        Self::new(AssetSource::Directory(root.into()))
+    }
+
+    /// Serve files from a map of paths to embedded contents.
+    pub fn from_embedded<K: Into<String>>(
+        assets: impl IntoIterator<Item = (K, &'static [u8])>,
+    ) -> Self {
+        Self::new(AssetSource::Embedded(
+            assets
+                .into_iter()
+                .map(|(path, contents)| (path.into().trim_start_matches('/').to_owned(), contents))
+                .collect(),
+        ))
+    }
+
+    /// Serve files from a directory embedded with the `include_dir` crate.
+    #[cfg(feature = "include_dir")]
+    pub fn from_include_dir(dir: &'static include_dir::Dir<'static>) -> Self {
+        Self::new(AssetSource::IncludeDir(dir))
+    }
+
+    /// Register the handler for `scheme_name` and optionally only for `domain_name`. See
+    /// [register_scheme_handler_factory] for more documentation.
+    pub fn register(self, scheme_name: CefString, domain_name: Option<CefString>) -> bool {
+        register_scheme_handler_factory(scheme_name, domain_name, self)
+    }
+
+    fn find(&self, path: &str) -> Option<Asset> {
+        match &self.source {
+            AssetSource::Directory(root) => {
+                let path = root.join(path);
+                let file = File::open(&path).ok()?;
+                let metadata = file.metadata().ok()?;
+                if !metadata.is_file() {
+                    return None;
+                }
+                let modified = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+
+                Some(Asset {
+                    etag: format!("\"{:x}-{:x}\"", metadata.len(), modified),
+                    length: metadata.len(),
+                    body: Body::File(file),
+                })
+            }
+            AssetSource::Embedded(assets) => {
+                assets.get(path).map(|contents| Asset::from(*contents))
+            }
+            #[cfg(feature = "include_dir")]
+            AssetSource::IncludeDir(dir) => dir.get_file(path).map(|f| Asset::from(f.contents())),
+        }
+    }
+
+    fn is_dir(&self, path: &str) -> bool {
+        match &self.source {
+            AssetSource::Directory(root) => root.join(path).is_dir(),
+            AssetSource::Embedded(assets) => {
+                let prefix = format!("{}/", path);
+                path.is_empty() || assets.keys().any(|k| k.starts_with(&prefix))
+            }
+            #[cfg(feature = "include_dir")]
+            AssetSource::IncludeDir(dir) => path.is_empty() || dir.get_dir(path).is_some(),
+        }
+    }
+
+    /// Find the file for `path` and its precompressed variant if it's accepted.
+    fn resolve(&self, path: &str, accept_encoding: &str) -> Option<(String, Option<&str>, Asset)> {
+        let path = if self.is_dir(path) {
+            if path.is_empty() {
+                self.index_file.clone()
+            } else {
+                format!("{}/{}", path, self.index_file)
+            }
+        } else {
+            path.to_owned()
+        };
+
+        if self.precompressed {
+            for (encoding, extension) in [("br", "br"), ("gzip", "gz")] {
+                if accepts_encoding(accept_encoding, encoding) {
+                    if let Some(asset) = self.find(&format!("{}.{}", path, extension)) {
+                        return Some((path, Some(encoding), asset));
+                    }
+                }
+            }
+        }
+
+        self.find(&path).map(|asset| (path, None, asset))
+    }
+
+    fn respond(&self, request: &Request) -> StaticAssetResource {
+        let method = request
+            .get_method()
+            .map(|m| m.to_string())
+            .unwrap_or_default();
+        let url = request
+            .get_url()
+            .map(|url| url.to_string())
+            .unwrap_or_default();
+        let header = |name: &str| {
+            request
+                .get_header_by_name(CefString::new(name))
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        };
+
+        self.respond_to(&method, &url, header)
+    }
+
+    fn respond_to(
+        &self,
+        method: &str,
+        url: &str,
+        header: impl Fn(&str) -> String,
+    ) -> StaticAssetResource {
+        let is_head = method.eq_ignore_ascii_case("HEAD");
+        if !is_head && !method.eq_ignore_ascii_case("GET") {
+            return StaticAssetResource::status(405, "Method Not Allowed")
+                .header("Allow", "GET, HEAD");
+        }
+
+        let Some(path) = url_path(url) else {
+            return StaticAssetResource::status(400, "Bad Request");
+        };
+
+        let accept_encoding = header("Accept-Encoding");
+        let resolved = self.resolve(&path, &accept_encoding).or_else(|| {
+            let fallback = self.spa_fallback.as_ref()?;
+            let file_name = path.rsplit('/').next().unwrap_or_default();
+            if file_name.contains('.') {
+                None
+            } else {
+                self.resolve(fallback.trim_start_matches('/'), &accept_encoding)
+            }
+        });
+        let Some((path, encoding, asset)) = resolved else {
+            return StaticAssetResource::status(404, "Not Found");
+        };
+
+        let mut resource = StaticAssetResource::status(200, "OK")
+            .mime_type(mime_type(&path))
+            .header("ETag", &asset.etag)
+            .header("Accept-Ranges", "bytes");
+        if let Some(cache_control) = &self.cache_control {
+            resource = resource.header("Cache-Control", cache_control);
+        }
+        if self.precompressed {
+            resource = resource.header("Vary", "Accept-Encoding");
+        }
+        if let Some(encoding) = encoding {
+            resource = resource.header("Content-Encoding", encoding);
+        }
+
+        let if_none_match = header("If-None-Match");
+        if !if_none_match.is_empty()
+            && if_none_match
+                .split(',')
+                .any(|tag| tag.trim() == asset.etag || tag.trim() == "*")
+        {
+            resource.status = 304;
+            resource.status_text = "Not Modified";
+            return resource;
+        }
+
+        let range = header("Range");
+        let range = if range.is_empty() {
+            None
+        } else {
+            match ByteRange::parse(&range, asset.length) {
+                Some(range) => Some(range),
+                None => {
+                    resource.status = 416;
+                    resource.status_text = "Range Not Satisfiable";
+                    return resource.header("Content-Range", &format!("bytes */{}", asset.length));
+                }
+            }
+        };
+
+        let (start, length) = match range {
+            Some(range) => {
+                resource.status = 206;
+                resource.status_text = "Partial Content";
+                resource = resource.header("Content-Range", &range.content_range(asset.length));
+                (range.start, range.content_length())
+            }
+            None => (0, asset.length),
+        };
+
+        // The body starts at the beginning of the file, cef skips to the start of the range.
+        resource.length = length;
+        resource.remaining = start;
+        if !is_head {
+            resource.body = asset.body;
+            resource.remaining += length;
+        }
+
+        resource
+    }
+}
+
+impl SchemeHandlerFactory for StaticAssetHandler {
+    type ResourceHandler = StaticAssetResource;
+
+    fn create(
+        &self,
+        _browser: Option<Browser>,
+        _frame: Option<Frame>,
+        _scheme_name: CefString,
+        request: Request,
+    ) -> Option<StaticAssetResource> {
+        Some(self.respond(&request))
+    }
+}
+
+struct Asset {
+    etag: String,
+    length: u64,
+    body: Body,
+}
+
+impl From<&'static [u8]> for Asset {
+    fn from(contents: &'static [u8]) -> Self {
+        let mut hasher = DefaultHasher::new();
+        contents.hash(&mut hasher);
+
+        Asset {
+            etag: format!("\"{:x}-{:x}\"", contents.len(), hasher.finish()),
+            length: contents.len() as u64,
+            body: Body::Static(contents),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Body {
+    Empty,
+    Static(&'static [u8]),
+    File(File),
+}
+
+impl Body {
+    fn skip(&mut self, bytes: u64) -> std::io::Result<()> {
+        match self {
+            Body::Empty => {}
+            Body::Static(contents) => *contents = &contents[(bytes as usize).min(contents.len())..],
+            Body::File(file) => {
+                file.seek(SeekFrom::Current(bytes as i64))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Body::Empty => Ok(0),
+            Body::Static(contents) => {
+                let len = buf.len().min(contents.len());
+                buf[..len].copy_from_slice(&contents[..len]);
+                *contents = &contents[len..];
+                Ok(len)
+            }
+            Body::File(file) => file.read(buf),
+        }
+    }
+}
+
+/// The [ResourceHandler] created by [StaticAssetHandler] for each request.
+#[derive(Debug)]
+pub struct StaticAssetResource {
+    status: i32,
+    status_text: &'static str,
+    mime_type: Option<&'static str>,
+    headers: Vec<(String, String)>,
+    length: u64,
+    body: Body,
+    remaining: u64,
+}
+
+impl StaticAssetResource {
+    fn status(status: i32, status_text: &'static str) -> Self {
+        Self {
+            status,
+            status_text,
+            mime_type: None,
+            headers: Vec::new(),
+            length: 0,
+            body: Body::Empty,
+            remaining: 0,
+        }
+    }
+
+    fn mime_type(mut self, mime_type: &'static str) -> Self {
+        self.mime_type = Some(mime_type);
+        self
+    }
+
+    fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    fn skip_bytes(&mut self, bytes_to_skip: u64) -> SkipResult {
+        let bytes = bytes_to_skip.min(self.remaining);
+        match self.body.skip(bytes) {
+            Ok(()) => {
+                self.remaining -= bytes;
+                SkipResult::Skipped(bytes)
+            }
+            Err(_) => SkipResult::Failed(ErrorCode::ERR_FILE_NOT_FOUND),
+        }
+    }
+}
+
+impl ResourceHandler for StaticAssetResource {
+    fn open(&mut self, _request: Request, _callback: Callback) -> OpenResult {
+        OpenResult::Handle
+    }
+
+    fn get_response_headers(
+        &mut self,
+        response: Response,
+        _redirect_url: &mut Option<CefString>,
+    ) -> Option<u64> {
+        response.set_status(self.status);
+        response.set_status_text(CefString::new(self.status_text));
+        if let Some(mime_type) = self.mime_type {
+            response.set_mime_type(CefString::new(mime_type));
+        }
+        for (name, value) in &self.headers {
+            response.set_header_by_name(CefString::new(name), CefString::new(value), true);
+        }
+
+        Some(self.length)
+    }
+
+    fn skip(&mut self, bytes_to_skip: u64, _callback: ResourceSkipCallback) -> SkipResult {
+        self.skip_bytes(bytes_to_skip)
+    }
+
+    fn read(&mut self, data_out: &mut [u8]) -> ReadResult {
+        let len = data_out.len().min(self.remaining as usize);
+        if len == 0 {
+            return ReadResult::Complete;
+        }
+
+        match self.body.read(&mut data_out[..len]) {
+            Ok(0) => ReadResult::Complete,
+            Ok(read) => {
+                self.remaining -= read as u64;
+                ReadResult::Read(read)
+            }
+            Err(_) => ReadResult::Failed(ErrorCode::ERR_FAILED),
+        }
+    }
+}
+
+fn accepts_encoding(accept_encoding: &str, encoding: &str) -> bool {
+    accept_encoding.split(',').any(|value| {
+        let mut parts = value.split(';');
+        let name = parts.next().unwrap_or_default().trim();
+        let rejected = parts.any(|p| {
+            p.trim()
+                .strip_prefix("q=")
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .is_some_and(|q| q == 0.0)
+        });
+        name.eq_ignore_ascii_case(encoding) && !rejected
+    })
+}
+
+/// Extract the normalized, percent-decoded path from `url` without its leading `/`. Returns
+/// `None` if a segment could escape the root, like `..` or a Windows drive prefix such as `C:`.
+fn url_path(url: &str) -> Option<String> {
+    let url = url.split(['?', '#']).next().unwrap_or_default();
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or("/"),
+        None => url.split_once(':').map(|(_, rest)| rest).unwrap_or(url),
+    };
+
+    let decoded = percent_decode(path)?;
+    let mut segments = Vec::new();
+    for segment in decoded.split(['/', '\\']) {
+        if segment.is_empty() || segment == "." {
+            continue;
+        }
+        if segment.contains([':', '\0']) {
+            return None;
+        }
+        let mut components = Path::new(segment).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(_)), None) => segments.push(segment),
+            _ => return None,
+        }
+    }
+
+    Some(segments.join("/"))
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value.get(i + 1..i + 3)?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(decoded).ok()
+}
+
+fn mime_type(path: &str) -> &'static str {
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "js" | "mjs" => "text/javascript",
+        "json" | "map" => "application/json",
+        "txt" => "text/plain",
+        "xml" => "application/xml",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "wasm" => "application/wasm",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "webmanifest" => "application/manifest+json",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "bmp" => "image/bmp",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" | "oga" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "ogv" => "video/ogg",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_path_normalizes_the_path() {
+        assert_eq!(url_path("app://host/a/b.js").as_deref(), Some("a/b.js"));
+        assert_eq!(url_path("app://host").as_deref(), Some(""));
+        assert_eq!(url_path("app://host/").as_deref(), Some(""));
+        assert_eq!(
+            url_path("app://host/a//./b?x=../..#f").as_deref(),
+            Some("a/b")
+        );
+        assert_eq!(url_path("app://host/a%20b.txt").as_deref(), Some("a b.txt"));
+        assert_eq!(url_path("app:index.html").as_deref(), Some("index.html"));
+        assert_eq!(url_path("app://host/a\\b").as_deref(), Some("a/b"));
+    }
+
+    #[test]
+    fn url_path_rejects_traversal() {
+        assert_eq!(url_path("app://host/../etc/passwd"), None);
+        assert_eq!(url_path("app://host/a/../../b"), None);
+        assert_eq!(url_path("app://host/a/..\\..\\b"), None);
+        assert_eq!(url_path("app://host/%2e%2e/etc/passwd"), None);
+        assert_eq!(url_path("app://host/%2E%2E/etc/passwd"), None);
+        assert_eq!(url_path("app://host/a/.%2e/b"), None);
+        assert_eq!(url_path("app://host/a%2f..%2f..%2fb"), None);
+        assert_eq!(url_path("app://host/a%5c..%5cb"), None);
+    }
+
+    #[test]
+    fn url_path_rejects_prefixes_and_nul() {
+        assert_eq!(url_path("app://host/C:/Windows/win.ini"), None);
+        assert_eq!(url_path("app://host/%43%3a/Windows"), None);
+        assert_eq!(url_path("app://host/a/c:b"), None);
+        assert_eq!(url_path("app://host/a%00.html"), None);
+    }
+
+    #[test]
+    fn url_path_rejects_bad_escapes() {
+        assert_eq!(url_path("app://host/a%"), None);
+        assert_eq!(url_path("app://host/a%2"), None);
+        assert_eq!(url_path("app://host/a%zz"), None);
+        assert_eq!(url_path("app://host/a%ff"), None);
+    }
+
+    #[test]
+    fn percent_decode_decodes_escapes() {
+        assert_eq!(percent_decode("a%41%62").as_deref(), Some("aAb"));
+        assert_eq!(
+            percent_decode("%C3%A9t%c3%a9").as_deref(),
+            Some("\u{e9}t\u{e9}")
+        );
+        assert_eq!(percent_decode("100%25").as_deref(), Some("100%"));
+        assert_eq!(percent_decode("").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn percent_decode_rejects_bad_escapes() {
+        assert_eq!(percent_decode("%"), None);
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%g1"), None);
+        assert_eq!(percent_decode("%+1"), None);
+        assert_eq!(percent_decode("%-1"), None);
+        assert_eq!(percent_decode("%\u{e9}"), None);
+        assert_eq!(percent_decode("%C3"), None);
+    }
+
+    fn range(value: &str, total_length: u64) -> Option<(u64, u64)> {
+        ByteRange::parse(value, total_length).map(|range| (range.start, range.end))
+    }
+
+    #[test]
+    fn range_parses_single_ranges() {
+        assert_eq!(range("bytes=0-499", 1000), Some((0, 499)));
+        assert_eq!(range("bytes=500-", 1000), Some((500, 999)));
+        assert_eq!(range("bytes=-200", 1000), Some((800, 999)));
+        assert_eq!(range(" bytes= 10 - 20 ", 1000), Some((10, 20)));
+        assert_eq!(range("bytes=0-0", 1000), Some((0, 0)));
+        assert_eq!(range("bytes=999-999", 1000), Some((999, 999)));
+    }
+
+    #[test]
+    fn range_clamps_to_the_length() {
+        assert_eq!(range("bytes=0-5000", 1000), Some((0, 999)));
+        assert_eq!(range("bytes=-2000", 1000), Some((0, 999)));
+
+        let range = ByteRange::parse("bytes=900-", 1000).unwrap();
+        assert_eq!(range.content_length(), 100);
+        assert_eq!(range.content_range(1000), "bytes 900-999/1000");
+    }
+
+    #[test]
+    fn range_rejects_unsatisfiable_and_malformed_ranges() {
+        assert_eq!(range("bytes=1000-", 1000), None);
+        assert_eq!(range("bytes=1000-1001", 1000), None);
+        assert_eq!(range("bytes=5-2", 1000), None);
+        assert_eq!(range("bytes=-0", 1000), None);
+        assert_eq!(range("bytes=0-", 0), None);
+        assert_eq!(range("bytes=0-1,3-4", 1000), None);
+        assert_eq!(range("items=0-1", 1000), None);
+        assert_eq!(range("bytes=abc-", 1000), None);
+        assert_eq!(range("bytes=-", 1000), None);
+        assert_eq!(range("bytes=0", 1000), None);
+        assert_eq!(range("bytes=-1-2", 1000), None);
+    }
+
+    fn handler() -> StaticAssetHandler {
+        StaticAssetHandler::from_embedded([
+            ("/index.html", b"<html>" as &'static [u8]),
+            ("index.html.br", b"br"),
+            ("index.html.gz", b"gz"),
+            ("app.js", b"app"),
+            ("app.js.gz", b"gz"),
+            ("docs/index.html", b"docs"),
+        ])
+    }
+
+    fn resolve<'a>(
+        handler: &'a StaticAssetHandler,
+        path: &str,
+        accept_encoding: &str,
+    ) -> Option<(String, Option<&'a str>)> {
+        handler
+            .resolve(path, accept_encoding)
+            .map(|(path, encoding, _)| (path, encoding))
+    }
+
+    #[test]
+    fn resolve_prefers_accepted_precompressed_variants() {
+        let handler = handler();
+
+        assert_eq!(
+            resolve(&handler, "", "gzip, deflate, br"),
+            Some(("index.html".to_owned(), Some("br")))
+        );
+        assert_eq!(
+            resolve(&handler, "index.html", "gzip"),
+            Some(("index.html".to_owned(), Some("gzip")))
+        );
+        assert_eq!(
+            resolve(&handler, "index.html", "br;q=0, gzip;q=0.5"),
+            Some(("index.html".to_owned(), Some("gzip")))
+        );
+        assert_eq!(
+            resolve(&handler, "app.js", "br, gzip"),
+            Some(("app.js".to_owned(), Some("gzip")))
+        );
+        assert_eq!(
+            resolve(&handler, "app.js", "br"),
+            Some(("app.js".to_owned(), None))
+        );
+        assert_eq!(
+            resolve(&handler, "index.html", ""),
+            Some(("index.html".to_owned(), None))
+        );
+    }
+
+    #[test]
+    fn resolve_serves_index_files_and_skips_disabled_variants() {
+        let mut handler = handler();
+
+        assert_eq!(
+            resolve(&handler, "docs", "br"),
+            Some(("docs/index.html".to_owned(), None))
+        );
+        assert_eq!(resolve(&handler, "missing.js", "br"), None);
+
+        handler.precompressed = false;
+        assert_eq!(
+            resolve(&handler, "", "br, gzip"),
+            Some(("index.html".to_owned(), None))
+        );
+    }
+
+    /// Serve `data.bin` like cef does: read the headers, skip to the first byte of the range
+    /// and read the body.
+    fn fetch(method: &str, range: &str) -> (i32, u64, Vec<u8>) {
+        let data: &'static [u8] = expected(0..1000).leak();
+        let handler = StaticAssetHandler::from_embedded([("data.bin", data)]);
+        let mut resource = handler.respond_to(method, "app://host/data.bin", |name| match name {
+            "Range" => range.to_owned(),
+            _ => String::new(),
+        });
+
+        if let Some(start) = ByteRange::parse(range, 1000).map(|range| range.start) {
+            assert_eq!(resource.skip_bytes(start), SkipResult::Skipped(start));
+        }
+        let mut body = Vec::new();
+        let mut data_out = [0; 64];
+        loop {
+            match resource.read(&mut data_out) {
+                ReadResult::Read(read) => body.extend_from_slice(&data_out[..read]),
+                ReadResult::Complete => break,
+                result => panic!("unexpected {result:?}"),
+            }
+        }
+
+        (resource.status, resource.length, body)
+    }
+
+    fn expected(range: std::ops::Range<usize>) -> Vec<u8> {
+        range.map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn serves_the_full_body() {
+        let (status, length, body) = fetch("GET", "");
+        assert_eq!((status, length), (200, 1000));
+        assert_eq!(body, expected(0..1000));
+    }
+
+    #[test]
+    fn ranges_are_positioned_by_skip() {
+        let (status, length, body) = fetch("GET", "bytes=0-9");
+        assert_eq!((status, length), (206, 10));
+        assert_eq!(body, expected(0..10));
+
+        let (status, length, body) = fetch("GET", "bytes=500-999");
+        assert_eq!((status, length), (206, 500));
+        assert_eq!(body, expected(500..1000));
+
+        let (status, length, body) = fetch("GET", "bytes=900-949");
+        assert_eq!((status, length), (206, 50));
+        assert_eq!(body, expected(900..950));
+
+        let (status, length, body) = fetch("GET", "bytes=-1");
+        assert_eq!((status, length), (206, 1));
+        assert_eq!(body, expected(999..1000));
+    }
+
+    #[test]
+    fn head_requests_have_no_body() {
+        let (status, length, body) = fetch("HEAD", "");
+        assert_eq!((status, length), (200, 1000));
+        assert!(body.is_empty());
+
+        let (status, length, body) = fetch("HEAD", "bytes=900-949");
+        assert_eq!((status, length), (206, 50));
+        assert!(body.is_empty());
+    }
+}
@@ -9,7 +9,7 @@
 //! for free it manually.
 
 use cef_sys::{
-    cef_string_list_alloc, cef_string_list_append, cef_string_list_clear, cef_string_list_copy, cef_string_list_free, cef_string_list_size, cef_string_list_t, cef_string_list_value, cef_string_map_alloc, cef_string_map_append, cef_string_map_clear, cef_string_map_find, cef_string_map_free, cef_string_map_key, cef_string_map_size, cef_string_map_t, cef_string_map_value, cef_string_t, cef_string_userfree_utf16_t, cef_string_utf16_t
+    cef_string_list_alloc, cef_string_list_append, cef_string_list_clear, cef_string_list_copy, cef_string_list_free, cef_string_list_size, cef_string_list_t, cef_string_list_value, cef_string_map_alloc, cef_string_map_append, cef_string_map_clear, cef_string_map_find, cef_string_map_free, cef_string_map_key, cef_string_map_size, cef_string_map_t, cef_string_map_value, cef_string_multimap_alloc, cef_string_multimap_append, cef_string_multimap_clear, cef_string_multimap_enumerate, cef_string_multimap_find_count, cef_string_multimap_free, cef_string_multimap_key, cef_string_multimap_size, cef_string_multimap_t, cef_string_multimap_value, cef_string_t, cef_string_userfree_utf16_t, cef_string_utf16_t
 };
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -59,6 +59,18 @@ impl CefString {
         Ok(res)
     }
 
+    /// Copy the value into a raw [cef_string_utf16_t] out-parameter provided by cef. The copy is
+    /// owned by cef afterwards.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or point to a valid [cef_string_utf16_t] that cef allows to overwrite.
+    pub unsafe fn set_raw(&self, ptr: *mut cef_string_utf16_t) {
+        if !ptr.is_null() {
+            unsafe { cef_sys::cef_string_utf16_set(self.0.as_ptr(), self.0.len(), ptr, 1) };
+        }
+    }
+
     /// Get raw [cef_string_utf16_t] which doesn't have the ownership of the value.
     /// This should be used when you need to pass the `*const cef_string_utf16_t` to the function.
     pub fn get_raw(&self) -> cef_string_utf16_t {
@@ -100,11 +112,8 @@ pub unsafe fn parse_string_list(ptr: cef_string_list_t) -> Vec<String> {
     let count = cef_sys::cef_string_list_size(ptr);
     let mut res = Vec::with_capacity(count);
     for i in 0..count {
-        let value = null_mut();
-        if cef_sys::cef_string_list_value(ptr, i, value) > 0 {
-            if let Ok(v) = CefString::from_raw(value) {
-                res.push(v.to_string())
-            }
+        if let Some(v) = read_out_string(|value| cef_sys::cef_string_list_value(ptr, i, value)) {
+            res.push(v.to_string())
         }
     }
     res
@@ -143,12 +152,7 @@ impl CefStringList {
 
     /// Returns the value at the supplied index
     pub fn get(&self, index: usize) -> Option<CefString> {
-        let value = CefString::default();
-        if unsafe { cef_string_list_value(self.0, index, &mut value.get_raw()) } > 0 {
-            Some(value)
-        } else {
-            None
-        }
+        read_out_string(|value| unsafe { cef_string_list_value(self.0, index, value) })
     }
 
     /// Appends a new value at the end of the list
@@ -172,6 +176,16 @@ impl Clone for CefStringList {
     }
 }
 
+impl<V: Into<CefString>> FromIterator<V> for CefStringList {
+    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
+        let mut list = Self::default();
+        for value in iter {
+            list.append(value.into());
+        }
+        list
+    }
+}
+
 impl Drop for CefStringList {
     fn drop(&mut self) {
         unsafe { cef_string_list_free(self.0) };
@@ -247,3 +261,91 @@ impl Drop for CefStringMap {
         unsafe { cef_string_map_free(self.0) };
     }
 }
+
+/// Helper type to deal with Cef string multimap. Unlike [CefStringMap], a key can appear multiple
+/// times, which makes it suitable for HTTP headers.
+#[derive(Debug)]
+pub struct CefStringMultimap(cef_string_multimap_t);
+
+impl Default for CefStringMultimap {
+    fn default() -> Self {
+        Self(unsafe { cef_string_multimap_alloc() })
+    }
+}
+
+impl CefStringMultimap {
+    /// Returns the number of elements in the map
+    pub fn size(&self) -> usize {
+        unsafe { cef_string_multimap_size(self.0) }
+    }
+
+    /// Returns all values assigned to the supplied key in insertion order
+    pub fn get_all(&self, key: CefString) -> Vec<CefString> {
+        let key = key.get_raw();
+        let count = unsafe { cef_string_multimap_find_count(self.0, &key) };
+        (0..count)
+            .filter_map(|i| {
+                read_out_string(|value| unsafe {
+                    cef_string_multimap_enumerate(self.0, &key, i, value)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the key at the supplied index
+    pub fn get_key_at_index(&self, index: usize) -> Option<CefString> {
+        read_out_string(|key| unsafe { cef_string_multimap_key(self.0, index, key) })
+    }
+
+    /// Returns the value at the supplied index
+    pub fn get_index(&self, index: usize) -> Option<CefString> {
+        read_out_string(|value| unsafe { cef_string_multimap_value(self.0, index, value) })
+    }
+
+    /// Returns all key/value pairs in insertion order
+    pub fn to_vec(&self) -> Vec<(CefString, CefString)> {
+        (0..self.size())
+            .filter_map(|i| Some((self.get_key_at_index(i)?, self.get_index(i)?)))
+            .collect()
+    }
+
+    /// Appends a new key/value pair at the end of the map. Existing values for the key are kept.
+    pub fn append(&mut self, key: CefString, value: CefString) {
+        unsafe { cef_string_multimap_append(self.0, &key.get_raw(), &value.get_raw()) };
+    }
+
+    /// Clears the map
+    pub fn clear(&mut self) {
+        unsafe { cef_string_multimap_clear(self.0) }
+    }
+
+    pub(crate) fn get_raw_mut(&mut self) -> cef_string_multimap_t {
+        self.0
+    }
+}
+
+impl<K: Into<CefString>, V: Into<CefString>> FromIterator<(K, V)> for CefStringMultimap {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map.append(key.into(), value.into());
+        }
+        map
+    }
+}
+
+impl Drop for CefStringMultimap {
+    fn drop(&mut self) {
+        unsafe { cef_string_multimap_free(self.0) };
+    }
+}
+
+/// Call `f` with an empty string out-parameter and take the value cef wrote into it.
+fn read_out_string(f: impl FnOnce(*mut cef_string_t) -> i32) -> Option<CefString> {
+    let mut raw: cef_string_t = unsafe { std::mem::zeroed() };
+    let res = (f(&mut raw) > 0)
+        .then(|| unsafe { CefString::from_raw(&raw) }.ok())
+        .flatten();
+    unsafe { cef_sys::cef_string_utf16_clear(&mut raw) };
+    res
+}
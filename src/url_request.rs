@@ -0,0 +1,340 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    ptr::null_mut,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use cef_sys::{
+    cef_auth_callback_t, cef_string_t, cef_urlrequest_client_t, cef_urlrequest_create,
+    cef_urlrequest_t,
+};
+
+use crate::{
+    rc::RcImpl, request::Request, request_context::RequestContext, request_handler::AuthCallback,
+    response::Response, string::CefString, wrapper, ErrorCode, UrlRequestStatus,
+};
+
+wrapper!(
+    #[doc = "See [cef_urlrequest_t] for more documentation."]
+    #[doc = ""]
+    #[doc = "The methods must be called on the thread that created the request."]
+    #[derive(Debug, Clone)]
+    pub struct UrlRequest(cef_urlrequest_t);
+    pub fn cancel(&self);
+);
+
+impl UrlRequest {
+    /// Create a new URL request that is not associated with a browser or frame and start it.
+    /// If `context` is `None` the global request context is used. The `request` is marked as
+    /// read-only. Returns `None` if the request couldn't be created.
+    ///
+    /// This may only be called in the browser process, on a valid cef thread.
+    pub fn new<C: UrlRequestClient>(
+        request: Request,
+        client: C,
+        context: Option<RequestContext>,
+    ) -> Option<UrlRequest> {
+        let context = context
+            .map(|c| unsafe { c.into_raw() })
+            .unwrap_or(null_mut());
+        let raw = unsafe { cef_urlrequest_create(request.into_raw(), client.into_raw(), context) };
+
+        (!raw.is_null()).then(|| unsafe { UrlRequest::from_raw(raw) })
+    }
+
+    /// Send `request` and wait for the complete response.
+    ///
+    /// See [UrlRequest::new] for the parameters. The request can be canceled with
+    /// [UrlRequestFuture::cancel].
+    pub fn send(request: Request, context: Option<RequestContext>) -> UrlRequestFuture {
+        Self::send_with_progress(request, context, |_, _| {}, |_, _| {})
+    }
+
+    /// Like [UrlRequest::send], also reporting the progress. The closures are called on the
+    /// thread that created the request with the amount of bytes transferred and the total amount
+    /// of bytes, if known.
+    ///
+    /// Upload progress is only reported if [RequestFlags::REPORT_UPLOAD_PROGRESS] is set on the
+    /// request.
+    ///
+    /// [RequestFlags::REPORT_UPLOAD_PROGRESS]: crate::request::RequestFlags::REPORT_UPLOAD_PROGRESS
+    pub fn send_with_progress(
+        request: Request,
+        context: Option<RequestContext>,
+        on_upload_progress: impl FnMut(u64, Option<u64>) + Send + 'static,
+        on_download_progress: impl FnMut(u64, Option<u64>) + Send + 'static,
+    ) -> UrlRequestFuture {
+        let state = Arc::new(Mutex::new(State::default()));
+        let client = FutureClient {
+            state: state.clone(),
+            on_upload_progress: Mutex::new(on_upload_progress),
+            on_download_progress: Mutex::new(on_download_progress),
+        };
+
+        let request = Self::new(request, client, context);
+        if request.is_none() {
+            state.lock().unwrap().result = Some(Err(ErrorCode::ERR_FAILED));
+        }
+        UrlRequestFuture { request, state }
+    }
+
+    /// Returns the request object used to create this URL request. It is read-only.
+    pub fn get_request(&self) -> Option<Request> {
+        let request = self.0.get_request.map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!request.is_null()).then(|| unsafe { Request::from_raw(request) })
+    }
+
+    pub fn get_request_status(&self) -> UrlRequestStatus {
+        self.0
+            .get_request_status
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(UrlRequestStatus::UR_UNKNOWN)
+    }
+
+    /// Returns the request error if the status is `UR_CANCELED` or `UR_FAILED`, or `ERR_NONE`
+    /// otherwise.
+    pub fn get_request_error(&self) -> ErrorCode {
+        self.0
+            .get_request_error
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ErrorCode::ERR_NONE)
+    }
+
+    /// Returns the response, or `None` if no response information is available yet. It is
+    /// read-only.
+    pub fn get_response(&self) -> Option<Response> {
+        let response = self
+            .0
+            .get_response
+            .map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!response.is_null()).then(|| unsafe { Response::from_raw(response) })
+    }
+
+    /// Returns `true` if the response body was served from the cache.
+    pub fn response_was_cached(&self) -> bool {
+        self.0
+            .response_was_cached
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+}
+
+/// See [cef_urlrequest_client_t] for more documentation.
+///
+/// The methods are called on the thread that created the request unless otherwise indicated.
+/// [UrlRequestClient::get_auth_credentials] is called on the IO thread while the other methods
+/// may run, so the methods take `&self` and per-request state needs interior mutability.
+pub trait UrlRequestClient: Sized + Send + Sync {
+    /// The request has completed. Use [UrlRequest::get_request_status] to find out if it was
+    /// successful.
+    fn on_request_complete(&self, request: UrlRequest);
+
+    /// `total` is `None` for chunked uploads. Only called if
+    /// [RequestFlags::REPORT_UPLOAD_PROGRESS](crate::request::RequestFlags::REPORT_UPLOAD_PROGRESS)
+    /// is set on the request.
+    fn on_upload_progress(&self, _request: UrlRequest, _current: u64, _total: Option<u64>) {}
+
+    /// `total` is `None` if the size of the response isn't known.
+    fn on_download_progress(&self, _request: UrlRequest, _current: u64, _total: Option<u64>) {}
+
+    /// Called with the bytes received since the last call.
+    fn on_download_data(&self, _request: UrlRequest, _data: &[u8]) {}
+
+    /// Called on the IO thread when credentials are needed. Return `true` and call
+    /// [AuthCallback::cont] when the credentials are available. Return `false` to fall back to
+    /// the [RequestHandler](crate::request_handler::RequestHandler) of the associated browser, if
+    /// any, or cancel the request.
+    fn get_auth_credentials(
+        &self,
+        _is_proxy: bool,
+        _host: CefString,
+        _port: u16,
+        _realm: Option<CefString>,
+        _scheme: Option<CefString>,
+        _callback: AuthCallback,
+    ) -> bool {
+        false
+    }
+
+    fn into_raw(self) -> *mut cef_urlrequest_client_t {
+        let mut object: cef_urlrequest_client_t = unsafe { std::mem::zeroed() };
+
+        object.on_request_complete = Some(on_request_complete::<Self>);
+        object.on_upload_progress = Some(on_upload_progress::<Self>);
+        object.on_download_progress = Some(on_download_progress::<Self>);
+        object.on_download_data = Some(on_download_data::<Self>);
+        object.get_auth_credentials = Some(get_auth_credentials::<Self>);
+
+        RcImpl::new(object, self) as *mut _
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    data: Vec<u8>,
+    result: Option<Result<(Response, Vec<u8>), ErrorCode>>,
+    waker: Option<Waker>,
+}
+
+struct FutureClient<U, D> {
+    state: Arc<Mutex<State>>,
+    on_upload_progress: Mutex<U>,
+    on_download_progress: Mutex<D>,
+}
+
+impl<U, D> UrlRequestClient for FutureClient<U, D>
+where
+    U: FnMut(u64, Option<u64>) + Send,
+    D: FnMut(u64, Option<u64>) + Send,
+{
+    fn on_request_complete(&self, request: UrlRequest) {
+        let mut state = self.state.lock().unwrap();
+        let data = std::mem::take(&mut state.data);
+        let result = match (request.get_request_status(), request.get_response()) {
+            (UrlRequestStatus::UR_SUCCESS, Some(response)) => Ok((response, data)),
+            _ => match request.get_request_error() {
+                ErrorCode::ERR_NONE => Err(ErrorCode::ERR_ABORTED),
+                error => Err(error),
+            },
+        };
+
+        state.result = Some(result);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn on_upload_progress(&self, _request: UrlRequest, current: u64, total: Option<u64>) {
+        (self.on_upload_progress.lock().unwrap())(current, total);
+    }
+
+    fn on_download_progress(&self, _request: UrlRequest, current: u64, total: Option<u64>) {
+        (self.on_download_progress.lock().unwrap())(current, total);
+    }
+
+    fn on_download_data(&self, _request: UrlRequest, data: &[u8]) {
+        self.state.lock().unwrap().data.extend_from_slice(data);
+    }
+}
+
+/// The future returned by [UrlRequest::send]. It resolves to the response and its body, or the
+/// error that made the request fail.
+#[derive(Debug)]
+pub struct UrlRequestFuture {
+    request: Option<UrlRequest>,
+    state: Arc<Mutex<State>>,
+}
+
+impl UrlRequestFuture {
+    /// The underlying request, or `None` if it couldn't be created.
+    pub fn request(&self) -> Option<&UrlRequest> {
+        self.request.as_ref()
+    }
+
+    /// Cancel the request. The future resolves to an error. This must be called on the thread
+    /// that created the request.
+    pub fn cancel(&self) {
+        if let Some(request) = &self.request {
+            request.cancel();
+        }
+    }
+}
+
+impl Future for UrlRequestFuture {
+    type Output = Result<(Response, Vec<u8>), ErrorCode>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn optional_total(total: i64) -> Option<u64> {
+    (total >= 0).then_some(total as u64)
+}
+
+extern "C" fn on_request_complete<C: UrlRequestClient>(
+    this: *mut cef_urlrequest_client_t,
+    request: *mut cef_urlrequest_t,
+) {
+    let client: &RcImpl<_, C> = RcImpl::get(this);
+    let request = unsafe { UrlRequest::from_raw(request) };
+
+    client.interface.on_request_complete(request);
+}
+
+extern "C" fn on_upload_progress<C: UrlRequestClient>(
+    this: *mut cef_urlrequest_client_t,
+    request: *mut cef_urlrequest_t,
+    current: i64,
+    total: i64,
+) {
+    let client: &RcImpl<_, C> = RcImpl::get(this);
+    let request = unsafe { UrlRequest::from_raw(request) };
+
+    client
+        .interface
+        .on_upload_progress(request, current.max(0) as u64, optional_total(total));
+}
+
+extern "C" fn on_download_progress<C: UrlRequestClient>(
+    this: *mut cef_urlrequest_client_t,
+    request: *mut cef_urlrequest_t,
+    current: i64,
+    total: i64,
+) {
+    let client: &RcImpl<_, C> = RcImpl::get(this);
+    let request = unsafe { UrlRequest::from_raw(request) };
+
+    client
+        .interface
+        .on_download_progress(request, current.max(0) as u64, optional_total(total));
+}
+
+extern "C" fn on_download_data<C: UrlRequestClient>(
+    this: *mut cef_urlrequest_client_t,
+    request: *mut cef_urlrequest_t,
+    data: *const ::std::os::raw::c_void,
+    data_length: usize,
+) {
+    let client: &RcImpl<_, C> = RcImpl::get(this);
+    let request = unsafe { UrlRequest::from_raw(request) };
+    let data = if data.is_null() {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data as *const u8, data_length) }
+    };
+
+    client.interface.on_download_data(request, data);
+}
+
+extern "C" fn get_auth_credentials<C: UrlRequestClient>(
+    this: *mut cef_urlrequest_client_t,
+    is_proxy: ::std::os::raw::c_int,
+    host: *const cef_string_t,
+    port: ::std::os::raw::c_int,
+    realm: *const cef_string_t,
+    scheme: *const cef_string_t,
+    callback: *mut cef_auth_callback_t,
+) -> ::std::os::raw::c_int {
+    let client: &RcImpl<_, C> = RcImpl::get(this);
+    let host = unsafe { CefString::from_raw(host).unwrap_or_default() };
+    let realm = unsafe { CefString::from_raw(realm).ok() };
+    let scheme = unsafe { CefString::from_raw(scheme).ok() };
+    let callback = unsafe { AuthCallback::from_raw(callback) };
+
+    client
+        .interface
+        .get_auth_credentials(is_proxy > 0, host, port as u16, realm, scheme, callback)
+        .into()
+}
@@ -1,3 +1,10 @@
+use std::time::{Duration, SystemTime};
+
+use cef_sys::{
+    cef_basetime_t, cef_time_from_basetime, cef_time_from_doublet, cef_time_t,
+    cef_time_to_basetime, cef_time_to_doublet,
+};
+
 use crate::ResultCode;
 
 pub fn integer_to_error_code(integer: i32) -> Result<ResultCode, ()>{
@@ -30,4 +37,128 @@ pub fn integer_to_error_code(integer: i32) -> Result<ResultCode, ()>{
         x if x == ResultCode::CEF_RESULT_CODE_SANDBOX_FATAL_LAST as i32 => Ok(ResultCode::CEF_RESULT_CODE_SANDBOX_FATAL_LAST),
         _ => Err(()),
     }
-}
\ No newline at end of file
+}
+
+/// Convert [cef_basetime_t] to [SystemTime]. Returns `None` if the time is null or can't be
+/// represented.
+pub(crate) fn basetime_to_system_time(time: cef_basetime_t) -> Option<SystemTime> {
+    if time.val == 0 {
+        return None;
+    }
+
+    let mut cef_time: cef_time_t = unsafe { std::mem::zeroed() };
+    let mut seconds = 0.0;
+    unsafe {
+        if cef_time_from_basetime(time, &mut cef_time) == 0
+            || cef_time_to_doublet(&cef_time, &mut seconds) == 0
+        {
+            return None;
+        }
+    }
+
+    if seconds >= 0.0 {
+        SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs_f64(seconds))
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(Duration::from_secs_f64(-seconds))
+    }
+}
+
+/// Convert [SystemTime] to [cef_basetime_t]. Returns a null time if it can't be represented.
+pub(crate) fn system_time_to_basetime(time: SystemTime) -> cef_basetime_t {
+    let seconds = match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(err) => -err.duration().as_secs_f64(),
+    };
+
+    let mut cef_time: cef_time_t = unsafe { std::mem::zeroed() };
+    let mut basetime = cef_basetime_t { val: 0 };
+    unsafe {
+        if cef_time_from_doublet(seconds, &mut cef_time) == 0
+            || cef_time_to_basetime(&cef_time, &mut basetime) == 0
+        {
+            return cef_basetime_t { val: 0 };
+        }
+    }
+
+    basetime
+}
+
+/// Match `value` against a pattern where `*` matches any sequence of characters.
+pub(crate) fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = value.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_pattern_without_wildcard_is_exact() {
+        assert!(matches_pattern(
+            "https://example.com",
+            "https://example.com"
+        ));
+        assert!(!matches_pattern(
+            "https://example.com",
+            "https://example.com/"
+        ));
+        assert!(!matches_pattern(
+            "https://example.com",
+            "https://example.co"
+        ));
+        assert!(matches_pattern("", ""));
+        assert!(!matches_pattern("", "a"));
+    }
+
+    #[test]
+    fn matches_pattern_with_wildcards() {
+        assert!(matches_pattern("*", ""));
+        assert!(matches_pattern("*", "https://example.com"));
+        assert!(matches_pattern(
+            "https://*.example.com",
+            "https://a.b.example.com"
+        ));
+        assert!(!matches_pattern(
+            "https://*.example.com",
+            "https://example.com"
+        ));
+        assert!(!matches_pattern(
+            "https://*.example.com",
+            "https://evil.com/.example.co"
+        ));
+        assert!(matches_pattern(
+            "https://example.com/*",
+            "https://example.com/"
+        ));
+        assert!(matches_pattern(
+            "*/api/*.json",
+            "https://x/api/v1/data.json"
+        ));
+        assert!(!matches_pattern("*/api/*.json", "https://x/api/v1/data.js"));
+        assert!(matches_pattern("a*b*c", "abc"));
+        assert!(matches_pattern("a**c", "ac"));
+    }
+
+    #[test]
+    fn matches_pattern_does_not_reuse_characters() {
+        assert!(!matches_pattern("ab*ba", "aba"));
+        assert!(matches_pattern("ab*ba", "abba"));
+        assert!(!matches_pattern("a*aa", "aa"));
+    }
+}
@@ -1,6 +1,249 @@
-use cef_sys::{cef_dictionary_value_create, cef_dictionary_value_t, cef_list_value_create, cef_list_value_t};
+use cef_sys::{
+    cef_binary_value_create, cef_binary_value_t, cef_dictionary_value_create,
+    cef_dictionary_value_t, cef_list_value_create, cef_list_value_t, cef_value_create, cef_value_t,
+};
 
-use crate::wrapper;
+use crate::{
+    string::{CefString, CefStringError, CefStringList},
+    wrapper, ValueType,
+};
+
+wrapper!(
+    #[doc = "See [cef_value_t] for more documentation."]
+    #[doc = ""]
+    #[doc = "A value of any of the types in [ValueType]. Values read from a dictionary or list may"]
+    #[doc = "reference data owned by it."]
+    #[derive(Debug, Clone)]
+    pub struct Value(cef_value_t);
+);
+
+/// Creates a value of type [ValueType::VTYPE_NULL].
+impl Default for Value {
+    fn default() -> Self {
+        unsafe { Value::from_raw(cef_value_create()) }
+    }
+}
+
+impl Value {
+    /// Returns `true` if the underlying data is valid. This will always be `true` for simple
+    /// types. For complex types it becomes `false` if the owner of the data is destroyed.
+    pub fn is_valid(&self) -> bool {
+        self.0
+            .is_valid
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the underlying data is owned by another object.
+    pub fn is_owned(&self) -> bool {
+        self.0
+            .is_owned
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    /// Returns `true` if this value and `other` have the same content.
+    pub fn is_equal(&self, other: &Value) -> bool {
+        self.0
+            .is_equal
+            .map(|f| unsafe { f(self.0.get_raw(), other.clone().into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns a deep copy of this value. The copy is not read-only.
+    pub fn copy(&self) -> Option<Value> {
+        let value = self.0.copy.map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { Value::from_raw(value) })
+    }
+
+    pub fn get_type(&self) -> ValueType {
+        self.0
+            .get_type
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(ValueType::VTYPE_INVALID)
+    }
+
+    pub fn get_bool(&self) -> bool {
+        self.0
+            .get_bool
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_int(&self) -> i32 {
+        self.0
+            .get_int
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn get_double(&self) -> f64 {
+        self.0
+            .get_double
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0.0)
+    }
+
+    pub fn get_string(&self) -> Result<CefString, CefStringError> {
+        let get_string = self.0.get_string.unwrap();
+
+        unsafe { CefString::from_userfree_cef(get_string(self.0.get_raw())) }
+    }
+
+    /// Returns the binary value, or `None` if the value is not of that type.
+    pub fn get_binary(&self) -> Option<BinaryValue> {
+        let value = self.0.get_binary.map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { BinaryValue::from_raw(value) })
+    }
+
+    /// Returns the dictionary value, or `None` if the value is not of that type. Changes to the
+    /// dictionary are reflected in this value.
+    pub fn get_dictionary(&self) -> Option<DictionaryValue> {
+        let value = self
+            .0
+            .get_dictionary
+            .map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { DictionaryValue::from_raw(value) })
+    }
+
+    /// Returns the list value, or `None` if the value is not of that type. Changes to the list
+    /// are reflected in this value.
+    pub fn get_list(&self) -> Option<ListValue> {
+        let value = self.0.get_list.map(|f| unsafe { f(self.0.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { ListValue::from_raw(value) })
+    }
+
+    pub fn set_null(&self) -> bool {
+        self.0
+            .set_null
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_bool(&self, value: bool) -> bool {
+        self.0
+            .set_bool
+            .map(|f| unsafe { f(self.0.get_raw(), value.into()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_int(&self, value: i32) -> bool {
+        self.0
+            .set_int
+            .map(|f| unsafe { f(self.0.get_raw(), value) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_double(&self, value: f64) -> bool {
+        self.0
+            .set_double
+            .map(|f| unsafe { f(self.0.get_raw(), value) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_string(&self, value: CefString) -> bool {
+        self.0
+            .set_string
+            .map(|f| unsafe { f(self.0.get_raw(), &value.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn set_binary(&self, value: BinaryValue) -> bool {
+        self.0
+            .set_binary
+            .map(|f| unsafe { f(self.0.get_raw(), value.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Set the value to a dictionary. If `value` is already owned by another object it is copied.
+    pub fn set_dictionary(&self, value: DictionaryValue) -> bool {
+        self.0
+            .set_dictionary
+            .map(|f| unsafe { f(self.0.get_raw(), value.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Set the value to a list. If `value` is already owned by another object it is copied.
+    pub fn set_list(&self, value: ListValue) -> bool {
+        self.0
+            .set_list
+            .map(|f| unsafe { f(self.0.get_raw(), value.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        let res = Value::default();
+        res.set_bool(value);
+        res
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        let res = Value::default();
+        res.set_int(value);
+        res
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        let res = Value::default();
+        res.set_double(value);
+        res
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        CefString::from(value).into()
+    }
+}
+
+impl From<CefString> for Value {
+    fn from(value: CefString) -> Self {
+        let res = Value::default();
+        res.set_string(value);
+        res
+    }
+}
+
+impl From<BinaryValue> for Value {
+    fn from(value: BinaryValue) -> Self {
+        let res = Value::default();
+        res.set_binary(value);
+        res
+    }
+}
+
+impl From<DictionaryValue> for Value {
+    fn from(value: DictionaryValue) -> Self {
+        let res = Value::default();
+        res.set_dictionary(value);
+        res
+    }
+}
+
+impl From<ListValue> for Value {
+    fn from(value: ListValue) -> Self {
+        let res = Value::default();
+        res.set_list(value);
+        res
+    }
+}
 
 wrapper!(
     #[doc = "See [cef_list_value_t] for more documentation."]
@@ -12,8 +255,85 @@ impl Default for ListValue {
     fn default() -> Self {
         unsafe { ListValue::from_raw(cef_list_value_create()) }
     }
-    
-    // TO DO: Implement cef_list_value_t methods
+}
+
+impl ListValue {
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.0
+            .get_size
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    /// Resize the list. New values are null, values past `size` are removed.
+    pub fn set_size(&self, size: usize) -> bool {
+        self.0
+            .set_size
+            .map(|f| unsafe { f(self.0.get_raw(), size) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn clear(&self) -> bool {
+        self.0
+            .clear
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn remove(&self, index: usize) -> bool {
+        self.0
+            .remove
+            .map(|f| unsafe { f(self.0.get_raw(), index) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_type(&self, index: usize) -> ValueType {
+        self.0
+            .get_type
+            .map(|f| unsafe { f(self.0.get_raw(), index) })
+            .unwrap_or(ValueType::VTYPE_INVALID)
+    }
+
+    /// Returns the value at `index`. For complex types the value references data owned by this
+    /// list.
+    pub fn get_value(&self, index: usize) -> Option<Value> {
+        let value = self
+            .0
+            .get_value
+            .map(|f| unsafe { f(self.0.get_raw(), index) })?;
+
+        (!value.is_null()).then(|| unsafe { Value::from_raw(value) })
+    }
+
+    /// Set the value at `index`, growing the list if needed.
+    pub fn set_value(&self, index: usize, value: Value) -> bool {
+        self.0
+            .set_value
+            .map(|f| unsafe { f(self.0.get_raw(), index, value.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    /// Append `value` at the end of the list.
+    pub fn push(&self, value: Value) -> bool {
+        self.set_value(self.get_size(), value)
+    }
+}
+
+impl<V: Into<Value>> FromIterator<V> for ListValue {
+    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
+        let list = ListValue::default();
+        for value in iter {
+            list.push(value.into());
+        }
+        list
+    }
 }
 
 wrapper!(
@@ -26,6 +346,127 @@ impl Default for DictionaryValue {
     fn default() -> Self {
         unsafe { DictionaryValue::from_raw(cef_dictionary_value_create()) }
     }
+}
 
-    // TO DO: Implement cef_dictionary_value_t methods
+impl DictionaryValue {
+    pub fn is_read_only(&self) -> bool {
+        self.0
+            .is_read_only
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(true)
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.0
+            .get_size
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    pub fn clear(&self) -> bool {
+        self.0
+            .clear
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn has_key(&self, key: CefString) -> bool {
+        self.0
+            .has_key
+            .map(|f| unsafe { f(self.0.get_raw(), &key.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_keys(&self) -> Vec<CefString> {
+        let mut keys = CefStringList::default();
+        if let Some(f) = self.0.get_keys {
+            unsafe { f(self.0.get_raw(), keys.get_raw_mut()) };
+        }
+        (0..keys.size()).filter_map(|i| keys.get(i)).collect()
+    }
+
+    pub fn remove(&self, key: CefString) -> bool {
+        self.0
+            .remove
+            .map(|f| unsafe { f(self.0.get_raw(), &key.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_type(&self, key: CefString) -> ValueType {
+        self.0
+            .get_type
+            .map(|f| unsafe { f(self.0.get_raw(), &key.get_raw()) })
+            .unwrap_or(ValueType::VTYPE_INVALID)
+    }
+
+    /// Returns the value for `key`. For complex types the value references data owned by this
+    /// dictionary.
+    pub fn get_value(&self, key: CefString) -> Option<Value> {
+        let value = self
+            .0
+            .get_value
+            .map(|f| unsafe { f(self.0.get_raw(), &key.get_raw()) })?;
+
+        (!value.is_null()).then(|| unsafe { Value::from_raw(value) })
+    }
+
+    pub fn set_value(&self, key: CefString, value: Value) -> bool {
+        self.0
+            .set_value
+            .map(|f| unsafe { f(self.0.get_raw(), &key.get_raw(), value.into_raw()) } > 0)
+            .unwrap_or(false)
+    }
+}
+
+impl<K: Into<CefString>, V: Into<Value>> FromIterator<(K, V)> for DictionaryValue {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let dictionary = DictionaryValue::default();
+        for (key, value) in iter {
+            dictionary.set_value(key.into(), value.into());
+        }
+        dictionary
+    }
+}
+
+wrapper!(
+    #[doc = "See [cef_binary_value_t] for more documentation."]
+    #[derive(Debug, Clone)]
+    pub struct BinaryValue(cef_binary_value_t);
+);
+
+impl BinaryValue {
+    /// Create a new binary value containing a copy of `data`.
+    pub fn new(data: &[u8]) -> Self {
+        unsafe {
+            BinaryValue::from_raw(cef_binary_value_create(
+                data.as_ptr() as *const _,
+                data.len(),
+            ))
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.0
+            .is_valid
+            .map(|f| unsafe { f(self.0.get_raw()) } > 0)
+            .unwrap_or(false)
+    }
+
+    pub fn get_size(&self) -> usize {
+        self.0
+            .get_size
+            .map(|f| unsafe { f(self.0.get_raw()) })
+            .unwrap_or(0)
+    }
+
+    /// Returns a copy of the data.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let size = self.get_size();
+        let mut data = vec![0; size];
+        if let Some(f) = self.0.get_data {
+            let read = unsafe { f(self.0.get_raw(), data.as_mut_ptr() as *mut _, size, 0) };
+            data.truncate(read);
+        }
+        data
+    }
 }
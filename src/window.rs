@@ -60,6 +60,18 @@ impl WindowInfo {
             window: self.window as c_ulong,
         }
     }
+
+    /// Copy the info into a raw [cef_window_info_t] provided by cef, like an out-parameter. The
+    /// window name is copied and owned by cef afterwards.
+    pub(crate) fn set_raw(&self, raw: &mut cef_window_info_t) {
+        unsafe { self.window_name.set_raw(&mut raw.window_name) };
+        raw.bounds = self.bounds.clone().into_raw();
+        raw.parent_window = self.parent_window as c_ulong;
+        raw.windowless_rendering_enabled = self.windowless_rendering_enabled as c_int;
+        raw.shared_texture_enabled = self.shared_texture_enabled as c_int;
+        raw.external_begin_frame_enabled = self.external_begin_frame_enabled as c_int;
+        raw.window = self.window as c_ulong;
+    }
 }
 
 impl From<&cef_window_info_t> for WindowInfo {
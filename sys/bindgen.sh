@@ -4,4 +4,5 @@ bindgen wrapper.h -o src/bindings.rs \
     --allowlist-type cef_.* \
     --allowlist-function cef_.* \
     --bitfield-enum .*_mask_t \
+    --bitfield-enum cef_.*_flags_t \
     -- -I $CEF_PATH
@@ -3330,30 +3330,72 @@ pub enum cef_transition_type_t {
     #[doc = "\n General mask defining the bits used for the qualifiers.\n"]
     TT_QUALIFIER_MASK = 4294967040,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[doc = "\n Flags used to customize the behavior of CefURLRequest.\n"]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_urlrequest_flags_t {
+impl cef_urlrequest_flags_t {
     #[doc = "\n Default behavior.\n"]
-    UR_FLAG_NONE = 0,
+    pub const UR_FLAG_NONE: cef_urlrequest_flags_t = cef_urlrequest_flags_t(0);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set the cache will be skipped when handling the request. Setting this\n value is equivalent to specifying the \"Cache-Control: no-cache\" request\n header. Setting this value in combination with UR_FLAG_ONLY_FROM_CACHE\n will cause the request to fail.\n"]
-    UR_FLAG_SKIP_CACHE = 1,
+    pub const UR_FLAG_SKIP_CACHE: cef_urlrequest_flags_t = cef_urlrequest_flags_t(1);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set the request will fail if it cannot be served from the cache (or\n some equivalent local store). Setting this value is equivalent to\n specifying the \"Cache-Control: only-if-cached\" request header. Setting\n this value in combination with UR_FLAG_SKIP_CACHE or UR_FLAG_DISABLE_CACHE\n will cause the request to fail.\n"]
-    UR_FLAG_ONLY_FROM_CACHE = 2,
+    pub const UR_FLAG_ONLY_FROM_CACHE: cef_urlrequest_flags_t = cef_urlrequest_flags_t(2);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set the cache will not be used at all. Setting this value is equivalent\n to specifying the \"Cache-Control: no-store\" request header. Setting this\n value in combination with UR_FLAG_ONLY_FROM_CACHE will cause the request\n to fail.\n"]
-    UR_FLAG_DISABLE_CACHE = 4,
+    pub const UR_FLAG_DISABLE_CACHE: cef_urlrequest_flags_t = cef_urlrequest_flags_t(4);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set user name, password, and cookies may be sent with the request, and\n cookies may be saved from the response.\n"]
-    UR_FLAG_ALLOW_STORED_CREDENTIALS = 8,
+    pub const UR_FLAG_ALLOW_STORED_CREDENTIALS: cef_urlrequest_flags_t = cef_urlrequest_flags_t(8);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set upload progress events will be generated when a request has a body.\n"]
-    UR_FLAG_REPORT_UPLOAD_PROGRESS = 16,
+    pub const UR_FLAG_REPORT_UPLOAD_PROGRESS: cef_urlrequest_flags_t = cef_urlrequest_flags_t(16);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set the CefURLRequestClient::OnDownloadData method will not be called.\n"]
-    UR_FLAG_NO_DOWNLOAD_DATA = 32,
+    pub const UR_FLAG_NO_DOWNLOAD_DATA: cef_urlrequest_flags_t = cef_urlrequest_flags_t(32);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set 5XX redirect errors will be propagated to the observer instead of\n automatically re-tried. This currently only applies for requests\n originated in the browser process.\n"]
-    UR_FLAG_NO_RETRY_ON_5XX = 64,
+    pub const UR_FLAG_NO_RETRY_ON_5XX: cef_urlrequest_flags_t = cef_urlrequest_flags_t(64);
+}
+impl cef_urlrequest_flags_t {
     #[doc = "\n If set 3XX responses will cause the fetch to halt immediately rather than\n continue through the redirect.\n"]
-    UR_FLAG_STOP_ON_REDIRECT = 128,
+    pub const UR_FLAG_STOP_ON_REDIRECT: cef_urlrequest_flags_t = cef_urlrequest_flags_t(128);
+}
+impl ::std::ops::BitOr<cef_urlrequest_flags_t> for cef_urlrequest_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_urlrequest_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_urlrequest_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_urlrequest_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_urlrequest_flags_t> for cef_urlrequest_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_urlrequest_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_urlrequest_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_urlrequest_flags_t) {
+        self.0 &= rhs.0;
+    }
 }
+#[repr(transparent)]
+#[doc = "\n Flags used to customize the behavior of CefURLRequest.\n"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cef_urlrequest_flags_t(pub ::std::os::raw::c_uint);
 #[repr(u32)]
 #[non_exhaustive]
 #[doc = "\n Flags that represent CefURLRequest status.\n"]
@@ -3877,34 +3919,88 @@ pub enum cef_paint_element_type_t {
     PET_VIEW = 0,
     PET_POPUP = 1,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[doc = "\n Supported event bit flags.\n"]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_event_flags_t {
-    EVENTFLAG_NONE = 0,
-    EVENTFLAG_CAPS_LOCK_ON = 1,
-    EVENTFLAG_SHIFT_DOWN = 2,
-    EVENTFLAG_CONTROL_DOWN = 4,
-    EVENTFLAG_ALT_DOWN = 8,
-    EVENTFLAG_LEFT_MOUSE_BUTTON = 16,
-    EVENTFLAG_MIDDLE_MOUSE_BUTTON = 32,
-    EVENTFLAG_RIGHT_MOUSE_BUTTON = 64,
+impl cef_event_flags_t {
+    pub const EVENTFLAG_NONE: cef_event_flags_t = cef_event_flags_t(0);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_CAPS_LOCK_ON: cef_event_flags_t = cef_event_flags_t(1);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_SHIFT_DOWN: cef_event_flags_t = cef_event_flags_t(2);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_CONTROL_DOWN: cef_event_flags_t = cef_event_flags_t(4);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_ALT_DOWN: cef_event_flags_t = cef_event_flags_t(8);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_LEFT_MOUSE_BUTTON: cef_event_flags_t = cef_event_flags_t(16);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_MIDDLE_MOUSE_BUTTON: cef_event_flags_t = cef_event_flags_t(32);
+}
+impl cef_event_flags_t {
+    pub const EVENTFLAG_RIGHT_MOUSE_BUTTON: cef_event_flags_t = cef_event_flags_t(64);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_COMMAND_DOWN = 128,
+    pub const EVENTFLAG_COMMAND_DOWN: cef_event_flags_t = cef_event_flags_t(128);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_NUM_LOCK_ON = 256,
+    pub const EVENTFLAG_NUM_LOCK_ON: cef_event_flags_t = cef_event_flags_t(256);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_IS_KEY_PAD = 512,
+    pub const EVENTFLAG_IS_KEY_PAD: cef_event_flags_t = cef_event_flags_t(512);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_IS_LEFT = 1024,
+    pub const EVENTFLAG_IS_LEFT: cef_event_flags_t = cef_event_flags_t(1024);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_IS_RIGHT = 2048,
+    pub const EVENTFLAG_IS_RIGHT: cef_event_flags_t = cef_event_flags_t(2048);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_ALTGR_DOWN = 4096,
+    pub const EVENTFLAG_ALTGR_DOWN: cef_event_flags_t = cef_event_flags_t(4096);
+}
+impl cef_event_flags_t {
     #[doc = " Mac OS-X command key."]
-    EVENTFLAG_IS_REPEAT = 8192,
+    pub const EVENTFLAG_IS_REPEAT: cef_event_flags_t = cef_event_flags_t(8192);
+}
+impl ::std::ops::BitOr<cef_event_flags_t> for cef_event_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_event_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_event_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_event_flags_t) {
+        self.0 |= rhs.0;
+    }
 }
+impl ::std::ops::BitAnd<cef_event_flags_t> for cef_event_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_event_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_event_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_event_flags_t) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
+#[doc = "\n Supported event bit flags.\n"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cef_event_flags_t(pub ::std::os::raw::c_uint);
 #[repr(u32)]
 #[non_exhaustive]
 #[doc = "\n Supported menu item types.\n"]
@@ -3917,26 +4013,66 @@ pub enum cef_menu_item_type_t {
     MENUITEMTYPE_SEPARATOR = 4,
     MENUITEMTYPE_SUBMENU = 5,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[doc = "\n Supported context menu type flags.\n"]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_context_menu_type_flags_t {
+impl cef_context_menu_type_flags_t {
     #[doc = "\n No node is selected.\n"]
-    CM_TYPEFLAG_NONE = 0,
+    pub const CM_TYPEFLAG_NONE: cef_context_menu_type_flags_t = cef_context_menu_type_flags_t(0);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n The top page is selected.\n"]
-    CM_TYPEFLAG_PAGE = 1,
+    pub const CM_TYPEFLAG_PAGE: cef_context_menu_type_flags_t = cef_context_menu_type_flags_t(1);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n A subframe page is selected.\n"]
-    CM_TYPEFLAG_FRAME = 2,
+    pub const CM_TYPEFLAG_FRAME: cef_context_menu_type_flags_t = cef_context_menu_type_flags_t(2);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n A link is selected.\n"]
-    CM_TYPEFLAG_LINK = 4,
+    pub const CM_TYPEFLAG_LINK: cef_context_menu_type_flags_t = cef_context_menu_type_flags_t(4);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n A media node is selected.\n"]
-    CM_TYPEFLAG_MEDIA = 8,
+    pub const CM_TYPEFLAG_MEDIA: cef_context_menu_type_flags_t = cef_context_menu_type_flags_t(8);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n There is a textual or mixed selection that is selected.\n"]
-    CM_TYPEFLAG_SELECTION = 16,
+    pub const CM_TYPEFLAG_SELECTION: cef_context_menu_type_flags_t =
+        cef_context_menu_type_flags_t(16);
+}
+impl cef_context_menu_type_flags_t {
     #[doc = "\n An editable element is selected.\n"]
-    CM_TYPEFLAG_EDITABLE = 32,
+    pub const CM_TYPEFLAG_EDITABLE: cef_context_menu_type_flags_t =
+        cef_context_menu_type_flags_t(32);
+}
+impl ::std::ops::BitOr<cef_context_menu_type_flags_t> for cef_context_menu_type_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_context_menu_type_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_context_menu_type_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_context_menu_type_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_context_menu_type_flags_t> for cef_context_menu_type_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_context_menu_type_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_context_menu_type_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_context_menu_type_flags_t) {
+        self.0 &= rhs.0;
+    }
 }
+#[repr(transparent)]
+#[doc = "\n Supported context menu type flags.\n"]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct cef_context_menu_type_flags_t(pub ::std::os::raw::c_uint);
 #[repr(u32)]
 #[non_exhaustive]
 #[doc = "\n Supported context menu media types. These constants match their equivalents\n in Chromium's ContextMenuDataMediaType and should not be renumbered.\n"]
@@ -3957,53 +4093,220 @@ pub enum cef_context_menu_media_type_t {
     #[doc = "\n A plugin node is selected.\n"]
     CM_MEDIATYPE_PLUGIN = 6,
 }
-#[repr(u32)]
-#[non_exhaustive]
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_NONE: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(0);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_IN_ERROR: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(1);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_PAUSED: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(2);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_MUTED: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(4);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_LOOP: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(8);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_SAVE: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(16);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_HAS_AUDIO: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(32);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_TOGGLE_CONTROLS: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(64);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CONTROLS: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(128);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_PRINT: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(256);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_ROTATE: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(512);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_PICTURE_IN_PICTURE: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(1024);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_PICTURE_IN_PICTURE: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(2048);
+}
+impl cef_context_menu_media_state_flags_t {
+    pub const CM_MEDIAFLAG_CAN_LOOP: cef_context_menu_media_state_flags_t =
+        cef_context_menu_media_state_flags_t(4096);
+}
+impl ::std::ops::BitOr<cef_context_menu_media_state_flags_t>
+    for cef_context_menu_media_state_flags_t
+{
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_context_menu_media_state_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_context_menu_media_state_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_context_menu_media_state_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_context_menu_media_state_flags_t>
+    for cef_context_menu_media_state_flags_t
+{
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_context_menu_media_state_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_context_menu_media_state_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_context_menu_media_state_flags_t) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
 #[doc = "\n Supported context menu media state bit flags. These constants match their\n equivalents in Chromium's ContextMenuData::MediaFlags and should not be\n renumbered.\n"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_context_menu_media_state_flags_t {
-    CM_MEDIAFLAG_NONE = 0,
-    CM_MEDIAFLAG_IN_ERROR = 1,
-    CM_MEDIAFLAG_PAUSED = 2,
-    CM_MEDIAFLAG_MUTED = 4,
-    CM_MEDIAFLAG_LOOP = 8,
-    CM_MEDIAFLAG_CAN_SAVE = 16,
-    CM_MEDIAFLAG_HAS_AUDIO = 32,
-    CM_MEDIAFLAG_CAN_TOGGLE_CONTROLS = 64,
-    CM_MEDIAFLAG_CONTROLS = 128,
-    CM_MEDIAFLAG_CAN_PRINT = 256,
-    CM_MEDIAFLAG_CAN_ROTATE = 512,
-    CM_MEDIAFLAG_CAN_PICTURE_IN_PICTURE = 1024,
-    CM_MEDIAFLAG_PICTURE_IN_PICTURE = 2048,
-    CM_MEDIAFLAG_CAN_LOOP = 4096,
+pub struct cef_context_menu_media_state_flags_t(pub ::std::os::raw::c_uint);
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_NONE: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(0);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_UNDO: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(1);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_REDO: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(2);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_CUT: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(4);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_COPY: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(8);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_PASTE: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(16);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_DELETE: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(32);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_SELECT_ALL: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(64);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_TRANSLATE: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(128);
+}
+impl cef_context_menu_edit_state_flags_t {
+    pub const CM_EDITFLAG_CAN_EDIT_RICHLY: cef_context_menu_edit_state_flags_t =
+        cef_context_menu_edit_state_flags_t(256);
+}
+impl ::std::ops::BitOr<cef_context_menu_edit_state_flags_t>
+    for cef_context_menu_edit_state_flags_t
+{
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_context_menu_edit_state_flags_t(self.0 | other.0)
+    }
 }
-#[repr(u32)]
-#[non_exhaustive]
+impl ::std::ops::BitOrAssign for cef_context_menu_edit_state_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_context_menu_edit_state_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_context_menu_edit_state_flags_t>
+    for cef_context_menu_edit_state_flags_t
+{
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_context_menu_edit_state_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_context_menu_edit_state_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_context_menu_edit_state_flags_t) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
 #[doc = "\n Supported context menu edit state bit flags. These constants match their\n equivalents in Chromium's ContextMenuDataEditFlags and should not be\n renumbered.\n"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_context_menu_edit_state_flags_t {
-    CM_EDITFLAG_NONE = 0,
-    CM_EDITFLAG_CAN_UNDO = 1,
-    CM_EDITFLAG_CAN_REDO = 2,
-    CM_EDITFLAG_CAN_CUT = 4,
-    CM_EDITFLAG_CAN_COPY = 8,
-    CM_EDITFLAG_CAN_PASTE = 16,
-    CM_EDITFLAG_CAN_DELETE = 32,
-    CM_EDITFLAG_CAN_SELECT_ALL = 64,
-    CM_EDITFLAG_CAN_TRANSLATE = 128,
-    CM_EDITFLAG_CAN_EDIT_RICHLY = 256,
+pub struct cef_context_menu_edit_state_flags_t(pub ::std::os::raw::c_uint);
+impl cef_quick_menu_edit_state_flags_t {
+    pub const QM_EDITFLAG_NONE: cef_quick_menu_edit_state_flags_t =
+        cef_quick_menu_edit_state_flags_t(0);
 }
-#[repr(u32)]
-#[non_exhaustive]
+impl cef_quick_menu_edit_state_flags_t {
+    pub const QM_EDITFLAG_CAN_ELLIPSIS: cef_quick_menu_edit_state_flags_t =
+        cef_quick_menu_edit_state_flags_t(1);
+}
+impl cef_quick_menu_edit_state_flags_t {
+    pub const QM_EDITFLAG_CAN_CUT: cef_quick_menu_edit_state_flags_t =
+        cef_quick_menu_edit_state_flags_t(2);
+}
+impl cef_quick_menu_edit_state_flags_t {
+    pub const QM_EDITFLAG_CAN_COPY: cef_quick_menu_edit_state_flags_t =
+        cef_quick_menu_edit_state_flags_t(4);
+}
+impl cef_quick_menu_edit_state_flags_t {
+    pub const QM_EDITFLAG_CAN_PASTE: cef_quick_menu_edit_state_flags_t =
+        cef_quick_menu_edit_state_flags_t(8);
+}
+impl ::std::ops::BitOr<cef_quick_menu_edit_state_flags_t> for cef_quick_menu_edit_state_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_quick_menu_edit_state_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_quick_menu_edit_state_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_quick_menu_edit_state_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_quick_menu_edit_state_flags_t> for cef_quick_menu_edit_state_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_quick_menu_edit_state_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_quick_menu_edit_state_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_quick_menu_edit_state_flags_t) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
 #[doc = "\n Supported quick menu state bit flags.\n"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_quick_menu_edit_state_flags_t {
-    QM_EDITFLAG_NONE = 0,
-    QM_EDITFLAG_CAN_ELLIPSIS = 1,
-    QM_EDITFLAG_CAN_CUT = 2,
-    QM_EDITFLAG_CAN_COPY = 4,
-    QM_EDITFLAG_CAN_PASTE = 8,
-}
+pub struct cef_quick_menu_edit_state_flags_t(pub ::std::os::raw::c_uint);
 #[repr(u32)]
 #[non_exhaustive]
 #[doc = "\n Key event types.\n"]
@@ -5645,17 +5948,55 @@ pub enum cef_show_state_t {
     CEF_SHOW_STATE_FULLSCREEN = 4,
     CEF_SHOW_STATE_HIDDEN = 5,
 }
-#[repr(u32)]
-#[non_exhaustive]
+impl cef_touch_handle_state_flags_t {
+    pub const CEF_THS_FLAG_NONE: cef_touch_handle_state_flags_t = cef_touch_handle_state_flags_t(0);
+}
+impl cef_touch_handle_state_flags_t {
+    pub const CEF_THS_FLAG_ENABLED: cef_touch_handle_state_flags_t =
+        cef_touch_handle_state_flags_t(1);
+}
+impl cef_touch_handle_state_flags_t {
+    pub const CEF_THS_FLAG_ORIENTATION: cef_touch_handle_state_flags_t =
+        cef_touch_handle_state_flags_t(2);
+}
+impl cef_touch_handle_state_flags_t {
+    pub const CEF_THS_FLAG_ORIGIN: cef_touch_handle_state_flags_t =
+        cef_touch_handle_state_flags_t(4);
+}
+impl cef_touch_handle_state_flags_t {
+    pub const CEF_THS_FLAG_ALPHA: cef_touch_handle_state_flags_t =
+        cef_touch_handle_state_flags_t(8);
+}
+impl ::std::ops::BitOr<cef_touch_handle_state_flags_t> for cef_touch_handle_state_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        cef_touch_handle_state_flags_t(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for cef_touch_handle_state_flags_t {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: cef_touch_handle_state_flags_t) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<cef_touch_handle_state_flags_t> for cef_touch_handle_state_flags_t {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        cef_touch_handle_state_flags_t(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for cef_touch_handle_state_flags_t {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: cef_touch_handle_state_flags_t) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
 #[doc = "\n Values indicating what state of the touch handle is set.\n"]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
-pub enum cef_touch_handle_state_flags_t {
-    CEF_THS_FLAG_NONE = 0,
-    CEF_THS_FLAG_ENABLED = 1,
-    CEF_THS_FLAG_ORIENTATION = 2,
-    CEF_THS_FLAG_ORIGIN = 4,
-    CEF_THS_FLAG_ALPHA = 8,
-}
+pub struct cef_touch_handle_state_flags_t(pub ::std::os::raw::c_uint);
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _cef_touch_handle_state_t {
@@ -10504,16 +10845,274 @@ fn bindgen_test_layout__cef_string_visitor_t() {
 }
 #[doc = "\n Implement this structure to receive string values asynchronously.\n"]
 pub type cef_string_visitor_t = _cef_string_visitor_t;
+#[doc = "\n Structure used to make a URL request. URL requests are not associated with a\n browser instance so no cef_client_t callbacks will be executed. URL requests\n can be created on any valid CEF thread in either the browser or render\n process. Once created the functions of the URL request object must be\n accessed on the same thread that created it.\n"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _cef_urlrequest_client_t {
-    _unused: [u8; 0],
+pub struct _cef_urlrequest_t {
+    #[doc = "\n Base structure.\n"]
+    pub base: cef_base_ref_counted_t,
+    #[doc = "\n Returns the request object used to create this URL request. The returned\n object is read-only and should not be modified.\n"]
+    pub get_request: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> *mut _cef_request_t,
+    >,
+    #[doc = "\n Returns the client.\n"]
+    pub get_client: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> *mut _cef_urlrequest_client_t,
+    >,
+    #[doc = "\n Returns the request status.\n"]
+    pub get_request_status: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> cef_urlrequest_status_t,
+    >,
+    #[doc = "\n Returns the request error if status is UR_CANCELED or UR_FAILED, or 0\n otherwise.\n"]
+    pub get_request_error: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> cef_errorcode_t,
+    >,
+    #[doc = "\n Returns the response, or NULL if no response information is available.\n Response information will only be available after the upload has\n completed. The returned object is read-only and should not be modified.\n"]
+    pub get_response: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> *mut _cef_response_t,
+    >,
+    #[doc = "\n Returns true (1) if the response body was served from the cache. This\n includes responses for which revalidation was required.\n"]
+    pub response_was_cached: ::std::option::Option<
+        unsafe extern "C" fn(self_: *mut _cef_urlrequest_t) -> ::std::os::raw::c_int,
+    >,
+    #[doc = "\n Cancel the request.\n"]
+    pub cancel: ::std::option::Option<unsafe extern "C" fn(self_: *mut _cef_urlrequest_t)>,
+}
+#[test]
+fn bindgen_test_layout__cef_urlrequest_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_cef_urlrequest_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_cef_urlrequest_t>(),
+        96usize,
+        concat!("Size of: ", stringify!(_cef_urlrequest_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_cef_urlrequest_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_cef_urlrequest_t))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).base) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_request) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(get_request)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_client) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(get_client)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_request_status) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(get_request_status)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_request_error) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(get_request_error)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_response) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(get_response)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).response_was_cached) as usize - ptr as usize },
+        80usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(response_was_cached)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).cancel) as usize - ptr as usize },
+        88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_t),
+            "::",
+            stringify!(cancel)
+        )
+    );
+}
+#[doc = "\n Structure used to make a URL request. URL requests are not associated with a\n browser instance so no cef_client_t callbacks will be executed. URL requests\n can be created on any valid CEF thread in either the browser or render\n process. Once created the functions of the URL request object must be\n accessed on the same thread that created it.\n"]
+pub type cef_urlrequest_t = _cef_urlrequest_t;
+extern "C" {
+    #[doc = "\n Create a new URL request that is not associated with a specific browser or\n frame. Use cef_frame_t::CreateURLRequest instead if you want the request to\n have this association, in which case it may be handled differently (see\n documentation on that function). A request created with this function may\n only originate from the browser process, and will behave as follows:\n   - It may be intercepted by the client via CefResourceRequestHandler or\n     CefSchemeHandlerFactory.\n   - POST data may only contain only a single element of type PDE_TYPE_FILE\n     or PDE_TYPE_BYTES.\n   - If |request_context| is empty the global request context will be used.\n\n The |request| object will be marked as read-only after calling this\n function.\n"]
+    pub fn cef_urlrequest_create(
+        request: *mut _cef_request_t,
+        client: *mut _cef_urlrequest_client_t,
+        request_context: *mut _cef_request_context_t,
+    ) -> *mut cef_urlrequest_t;
 }
+#[doc = "\n Structure that should be implemented by the cef_urlrequest_t client. The\n functions of this structure will be called on the same thread that created\n the request unless otherwise documented.\n"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct _cef_urlrequest_t {
-    _unused: [u8; 0],
+pub struct _cef_urlrequest_client_t {
+    #[doc = "\n Base structure.\n"]
+    pub base: cef_base_ref_counted_t,
+    #[doc = "\n Notifies the client that the request has completed. Use the\n cef_urlrequest_t::GetRequestStatus function to determine if the request\n was successful or not.\n"]
+    pub on_request_complete: ::std::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut _cef_urlrequest_client_t,
+            request: *mut _cef_urlrequest_t,
+        ),
+    >,
+    #[doc = "\n Notifies the client of upload progress. |current| denotes the number of\n bytes sent so far and |total| is the total size of uploading data (or -1\n if chunked upload is enabled). This function will only be called if the\n UR_FLAG_REPORT_UPLOAD_PROGRESS flag is set on the request.\n"]
+    pub on_upload_progress: ::std::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut _cef_urlrequest_client_t,
+            request: *mut _cef_urlrequest_t,
+            current: i64,
+            total: i64,
+        ),
+    >,
+    #[doc = "\n Notifies the client of download progress. |current| denotes the number of\n bytes received up to the call and |total| is the expected total size of\n the response (or -1 if not determined).\n"]
+    pub on_download_progress: ::std::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut _cef_urlrequest_client_t,
+            request: *mut _cef_urlrequest_t,
+            current: i64,
+            total: i64,
+        ),
+    >,
+    #[doc = "\n Called when some part of the response is read. |data| contains the current\n bytes received since the last call. This function will not be called if\n the UR_FLAG_NO_DOWNLOAD_DATA flag is set on the request.\n"]
+    pub on_download_data: ::std::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut _cef_urlrequest_client_t,
+            request: *mut _cef_urlrequest_t,
+            data: *const ::std::os::raw::c_void,
+            data_length: usize,
+        ),
+    >,
+    #[doc = "\n Called on the IO thread when the browser needs credentials from the user.\n |isProxy| indicates whether the host is a proxy server. |host| contains the\n hostname and |port| contains the port number. Return true (1) to continue\n the request and call cef_auth_callback_t::cont() when the authentication\n information is available. If the request has an associated browser/frame\n then returning false (0) will result in a call to GetAuthCredentials on\n the cef_request_handler_t associated with that browser, if any. Otherwise,\n returning false (0) will cancel the request immediately. This function\n will only be called for requests initiated from the browser process.\n"]
+    pub get_auth_credentials: ::std::option::Option<
+        unsafe extern "C" fn(
+            self_: *mut _cef_urlrequest_client_t,
+            isProxy: ::std::os::raw::c_int,
+            host: *const cef_string_t,
+            port: ::std::os::raw::c_int,
+            realm: *const cef_string_t,
+            scheme: *const cef_string_t,
+            callback: *mut _cef_auth_callback_t,
+        ) -> ::std::os::raw::c_int,
+    >,
+}
+#[test]
+fn bindgen_test_layout__cef_urlrequest_client_t() {
+    const UNINIT: ::std::mem::MaybeUninit<_cef_urlrequest_client_t> = ::std::mem::MaybeUninit::uninit();
+    let ptr = UNINIT.as_ptr();
+    assert_eq!(
+        ::std::mem::size_of::<_cef_urlrequest_client_t>(),
+        80usize,
+        concat!("Size of: ", stringify!(_cef_urlrequest_client_t))
+    );
+    assert_eq!(
+        ::std::mem::align_of::<_cef_urlrequest_client_t>(),
+        8usize,
+        concat!("Alignment of ", stringify!(_cef_urlrequest_client_t))
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).base) as usize - ptr as usize },
+        0usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(base)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).on_request_complete) as usize - ptr as usize },
+        40usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(on_request_complete)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).on_upload_progress) as usize - ptr as usize },
+        48usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(on_upload_progress)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).on_download_progress) as usize - ptr as usize },
+        56usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(on_download_progress)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).on_download_data) as usize - ptr as usize },
+        64usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(on_download_data)
+        )
+    );
+    assert_eq!(
+        unsafe { ::std::ptr::addr_of!((*ptr).get_auth_credentials) as usize - ptr as usize },
+        72usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(_cef_urlrequest_client_t),
+            "::",
+            stringify!(get_auth_credentials)
+        )
+    );
 }
+#[doc = "\n Structure that should be implemented by the cef_urlrequest_t client. The\n functions of this structure will be called on the same thread that created\n the request unless otherwise documented.\n"]
+pub type cef_urlrequest_client_t = _cef_urlrequest_client_t;
 #[doc = "\n Structure used to represent a frame in the browser window. When used in the\n browser process the functions of this structure may be called on any thread\n unless otherwise indicated in the comments. When used in the render process\n the functions of this structure may only be called on the main thread.\n"]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]